@@ -1,2 +1,11 @@
 pub mod stars;
-pub mod planets;
\ No newline at end of file
+pub mod planets;
+pub mod orbits;
+pub mod rings;
+pub mod belts;
+pub mod scans;
+pub mod atmospheres;
+pub mod volcanism;
+pub mod composition;
+pub mod materials;
+pub mod exobiology;
@@ -0,0 +1,304 @@
+use std::fmt;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+
+/// Lowest surface pressure, in factors of Earth's atmosphere, that counts as an atmosphere at all.
+pub const MIN_ATMOSPHERE_PRESSURE: f64 = 0.001;
+
+/// Lowest surface gravity, in factors of Earth's gravity, able to hold on to any atmosphere.
+pub const MIN_ATMOSPHERE_GRAVITY: f64 = 0.02;
+
+/// Lowest surface gravity able to hold on to light gases (hydrogen, helium, neon and methane).
+pub const MIN_LIGHT_GAS_GRAVITY: f64 = 0.3;
+
+
+/// A gas found in planetary atmospheres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gas {
+    Hydrogen,
+    Helium,
+    Nitrogen,
+    Oxygen,
+    CarbonDioxide,
+    Ammonia,
+    Methane,
+    Water,
+    SulphurDioxide,
+    Argon,
+    Neon,
+    Iron,
+    Silicates,
+}
+
+impl Gas {
+    /// Returns a formatted *&str* of the *Gas*, e.g. "Carbon Dioxide".
+    pub fn fetch_name(&self) -> &'static str {
+        match self {
+            Gas::Hydrogen => "Hydrogen",
+            Gas::Helium => "Helium",
+            Gas::Nitrogen => "Nitrogen",
+            Gas::Oxygen => "Oxygen",
+            Gas::CarbonDioxide => "Carbon Dioxide",
+            Gas::Ammonia => "Ammonia",
+            Gas::Methane => "Methane",
+            Gas::Water => "Water",
+            Gas::SulphurDioxide => "Sulphur Dioxide",
+            Gas::Argon => "Argon",
+            Gas::Neon => "Neon",
+            Gas::Iron => "Iron",
+            Gas::Silicates => "Silicates",
+        }
+    }
+
+    /// Returns true for gases too light for low-gravity bodies to retain.
+    pub fn is_light(&self) -> bool {
+        matches!(self, Gas::Hydrogen | Gas::Helium | Gas::Neon | Gas::Methane)
+    }
+}
+
+
+/// The dominant chemistry of an atmosphere, which names it (e.g. "Thin Ammonia").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AtmosphereType {
+    Ammonia,
+    CarbonDioxide,
+    Nitrogen,
+    NitrogenOxygen,
+    SulphurDioxide,
+    Water,
+    Methane,
+    Argon,
+    Neon,
+    Helium,
+    Hydrogen,
+    MetallicVapour,
+    SilicateVapour,
+}
+
+impl AtmosphereType {
+    /// Returns a formatted *&str* of the *AtmosphereType*, e.g. "Sulphur Dioxide".
+    pub fn fetch_name(&self) -> &'static str {
+        match self {
+            AtmosphereType::Ammonia => "Ammonia",
+            AtmosphereType::CarbonDioxide => "Carbon Dioxide",
+            AtmosphereType::Nitrogen => "Nitrogen",
+            AtmosphereType::NitrogenOxygen => "Nitrogen-Oxygen",
+            AtmosphereType::SulphurDioxide => "Sulphur Dioxide",
+            AtmosphereType::Water => "Water",
+            AtmosphereType::Methane => "Methane",
+            AtmosphereType::Argon => "Argon",
+            AtmosphereType::Neon => "Neon",
+            AtmosphereType::Helium => "Helium",
+            AtmosphereType::Hydrogen => "Hydrogen",
+            AtmosphereType::MetallicVapour => "Metallic Vapour",
+            AtmosphereType::SilicateVapour => "Silicate Vapour",
+        }
+    }
+
+    /// Returns the main gas of the *AtmosphereType*, followed by the gases mixed into it.
+    pub fn gases(&self) -> (Gas, &'static [Gas]) {
+        match self {
+            AtmosphereType::Ammonia => (Gas::Ammonia, &[Gas::Nitrogen, Gas::Methane]),
+            AtmosphereType::CarbonDioxide => (Gas::CarbonDioxide, &[Gas::Nitrogen, Gas::Argon, Gas::SulphurDioxide]),
+            AtmosphereType::Nitrogen => (Gas::Nitrogen, &[Gas::Methane, Gas::Argon, Gas::CarbonDioxide]),
+            AtmosphereType::NitrogenOxygen => (Gas::Nitrogen, &[Gas::Oxygen, Gas::Argon, Gas::Water, Gas::CarbonDioxide]),
+            AtmosphereType::SulphurDioxide => (Gas::SulphurDioxide, &[Gas::CarbonDioxide, Gas::Nitrogen]),
+            AtmosphereType::Water => (Gas::Water, &[Gas::CarbonDioxide, Gas::Nitrogen, Gas::Oxygen]),
+            AtmosphereType::Methane => (Gas::Methane, &[Gas::Nitrogen, Gas::Argon]),
+            AtmosphereType::Argon => (Gas::Argon, &[Gas::Nitrogen, Gas::Neon]),
+            AtmosphereType::Neon => (Gas::Neon, &[Gas::Argon, Gas::Helium]),
+            AtmosphereType::Helium => (Gas::Helium, &[Gas::Hydrogen, Gas::Methane]),
+            AtmosphereType::Hydrogen => (Gas::Hydrogen, &[Gas::Helium, Gas::Methane, Gas::Ammonia]),
+            AtmosphereType::MetallicVapour => (Gas::Iron, &[Gas::Silicates, Gas::SulphurDioxide]),
+            AtmosphereType::SilicateVapour => (Gas::Silicates, &[Gas::Iron, Gas::SulphurDioxide, Gas::CarbonDioxide]),
+        }
+    }
+}
+
+
+/// How dense an atmosphere is, judged by its surface pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AtmosphereDensity {
+    Thin, // Below 0.1 atmospheres
+    Normal,
+    Thick, // Above 10 atmospheres
+}
+
+impl AtmosphereDensity {
+    /// Returns the *AtmosphereDensity* of an atmosphere with the given surface pressure (factors of Earth's atmosphere).
+    pub fn from_pressure(surface_pressure: f64) -> Self {
+        if surface_pressure < 0.1 {
+            AtmosphereDensity::Thin
+        }else if surface_pressure > 10.0 {
+            AtmosphereDensity::Thick
+        }else {
+            AtmosphereDensity::Normal
+        }
+    }
+
+    /// Returns a formatted *&str* of the *AtmosphereDensity*, empty for *Normal*.
+    pub fn fetch_name(&self) -> &'static str {
+        match self {
+            AtmosphereDensity::Thin => "Thin",
+            AtmosphereDensity::Normal => "",
+            AtmosphereDensity::Thick => "Thick",
+        }
+    }
+}
+
+
+/// The atmosphere of a planet: its type, how dense it is, and the share of each gas in it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Atmosphere {
+    pub atmosphere_type: AtmosphereType,
+    pub density: AtmosphereDensity,
+    pub composition: Vec<(Gas, f64)>, // Percentages summing to 100, largest first
+}
+
+impl Atmosphere {
+    /// Returns a randomly-generated *Atmosphere* for a planet of the given type identifier and surface conditions,
+    /// or *None* if the planet is airless or too light to hold on to any gas.
+    /// Gas giants are hydrogen-helium envelopes, ammonia worlds breathe ammonia and Earth-like worlds nitrogen and oxygen,
+    /// while the atmospheres of other bodies depend on which gases stay gaseous at their surface temperature.
+    pub fn random<R: Rng + ?Sized>(planet_type: &str, surface_temp: f64, surface_pressure: f64, gravity: f64, rng: &mut R) -> Option<Self> {
+        if surface_pressure < MIN_ATMOSPHERE_PRESSURE || gravity < MIN_ATMOSPHERE_GRAVITY {
+            return None;
+        };
+
+        let atmosphere_type: AtmosphereType = match planet_type {
+            "AW" => AtmosphereType::Ammonia,
+            "ELW" => AtmosphereType::NitrogenOxygen,
+            "WG" => AtmosphereType::Water,
+            "HGG" | "HRGG" => AtmosphereType::Helium,
+            "GGGG" | "CIGG" | "CIIGG" | "CIIIGG" | "CIVGG" | "CVGG" | "GGWABL" | "GGWWBL" => AtmosphereType::Hydrogen,
+            _ => {
+                let candidates: Vec<(AtmosphereType, f64)> = solid_candidates(planet_type, surface_temp)
+                    .into_iter()
+                    .filter(|(t, _)| gravity >= MIN_LIGHT_GAS_GRAVITY || !t.gases().0.is_light())
+                    .collect();
+                if candidates.is_empty() {
+                    return None;
+                };
+                let index: WeightedIndex<f64> = WeightedIndex::new(candidates.iter().map(|(_, w)| *w))
+                    .expect("Atmosphere weights are valid");
+                candidates[index.sample(rng)].0
+            },
+        };
+
+        Some(Atmosphere {
+            atmosphere_type,
+            density: AtmosphereDensity::from_pressure(surface_pressure),
+            composition: random_composition(atmosphere_type, gravity, rng),
+        })
+    }
+
+    /// Returns what remains of the *Atmosphere* on a body with the given surface gravity.
+    /// Light gases escape from weak gravity, and the whole atmosphere is lost if it was mostly made of them
+    /// or the body is too light to hold any gas at all.
+    pub fn held_by(mut self, gravity: f64) -> Option<Self> {
+        if gravity < MIN_ATMOSPHERE_GRAVITY {
+            return None;
+        };
+        if gravity >= MIN_LIGHT_GAS_GRAVITY {
+            return Some(self);
+        };
+        if self.atmosphere_type.gases().0.is_light() {
+            return None;
+        };
+
+        self.composition.retain(|(gas, _)| !gas.is_light());
+        let total: f64 = self.composition.iter().map(|(_, share)| share).sum();
+        self.composition.iter_mut().for_each(|(_, share)| *share *= 100.0 / total);
+        Some(self)
+    }
+
+    /// Returns the percentage of the given gas in the *Atmosphere*, or 0 if it is absent.
+    pub fn share_of(&self, gas: Gas) -> f64 {
+        self.composition.iter().find(|(g, _)| *g == gas).map_or(0.0, |(_, share)| *share)
+    }
+}
+
+impl fmt::Display for Atmosphere {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.density {
+            AtmosphereDensity::Normal => write!(f, "{}", self.atmosphere_type.fetch_name()),
+            density => write!(f, "{} {}", density.fetch_name(), self.atmosphere_type.fetch_name()),
+        }
+    }
+}
+
+
+/* Atmospheric Util Functions */
+
+/// Returns the atmosphere types a solid body of the given type could hold at the given surface temperature (Kelvin),
+/// weighted by how likely each is. Gases that would freeze out or break apart at that temperature are left out.
+fn solid_candidates(planet_type: &str, surface_temp: f64) -> Vec<(AtmosphereType, f64)> {
+    let vapour: AtmosphereType = if planet_type == "MRB" {
+        AtmosphereType::MetallicVapour
+    }else {
+        AtmosphereType::SilicateVapour
+    };
+    let watery: f64 = if planet_type == "WW" { 6.0 } else { 1.0 };
+
+    match surface_temp {
+        t if t >= 1_000.0 => vec![(vapour, 6.0), (AtmosphereType::SulphurDioxide, 2.0), (AtmosphereType::CarbonDioxide, 1.0)],
+        t if t >= 500.0 => vec![
+            (AtmosphereType::CarbonDioxide, 5.0),
+            (AtmosphereType::SulphurDioxide, 4.0),
+            (AtmosphereType::Water, 2.0 * watery),
+        ],
+        t if t >= 250.0 => vec![
+            (AtmosphereType::CarbonDioxide, 5.0),
+            (AtmosphereType::Nitrogen, 3.0),
+            (AtmosphereType::Water, if t >= 373.0 { 2.0 * watery } else { 0.5 * watery }),
+            (AtmosphereType::Argon, 1.0),
+        ],
+        t if t >= 150.0 => vec![
+            (AtmosphereType::Nitrogen, 4.0),
+            (AtmosphereType::CarbonDioxide, if t >= 195.0 { 3.0 } else { 0.0 }),
+            (AtmosphereType::Ammonia, if t >= 195.0 { 1.0 } else { 0.0 }),
+            (AtmosphereType::Methane, 2.0),
+            (AtmosphereType::Argon, 1.0),
+        ],
+        t if t >= 40.0 => vec![
+            (AtmosphereType::Nitrogen, 3.0),
+            (AtmosphereType::Methane, if t >= 91.0 { 3.0 } else { 1.0 }),
+            (AtmosphereType::Argon, 2.0),
+            (AtmosphereType::Neon, 1.0),
+        ],
+        _ => vec![(AtmosphereType::Neon, 3.0), (AtmosphereType::Helium, 1.0)],
+    }
+    .into_iter()
+    .filter(|(_, weight)| *weight > 0.0)
+    .collect()
+}
+
+/// Returns a random gas breakdown for an atmosphere of the given type, as percentages summing to 100, largest first.
+/// The main gas always makes up the majority, and bodies too light to hold light gases lose them entirely.
+fn random_composition<R: Rng + ?Sized>(atmosphere_type: AtmosphereType, gravity: f64, rng: &mut R) -> Vec<(Gas, f64)> {
+    let (main, trace): (Gas, &[Gas]) = atmosphere_type.gases();
+    let main_share: f64 = rng.gen_range(60.0..=97.0);
+
+    let trace: Vec<(Gas, f64)> = trace.iter()
+        .filter(|gas| gravity >= MIN_LIGHT_GAS_GRAVITY || !gas.is_light())
+        .map(|gas| (*gas, rng.gen_range(0.05..=1.0)))
+        .collect();
+    let trace_total: f64 = trace.iter().map(|(_, w)| w).sum();
+
+    let mut composition: Vec<(Gas, f64)> = if trace.is_empty() {
+        vec![(main, 100.0)]
+    }else {
+        std::iter::once((main, main_share))
+            .chain(trace.into_iter().map(|(gas, w)| (gas, (100.0 - main_share) * w / trace_total)))
+            .collect()
+    };
+    composition.sort_by(|a, b| b.1.total_cmp(&a.1));
+    composition
+}
@@ -0,0 +1,46 @@
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+use crate::bodies::orbits::log_uniform;
+use crate::bodies::rings::{RingClass, RING_CLASSES};
+
+
+/// A belt of asteroids orbiting a star. Belts share the four material classes of planetary rings.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AsteroidBelt {
+    pub name: String,
+    pub class: RingClass,
+    pub inner_radius: f64, // Light-Seconds
+    pub outer_radius: f64, // Light-Seconds
+    pub mass: f64, // Megatonnes
+}
+
+impl AsteroidBelt {
+    /// Returns a randomly-generated *AsteroidBelt* spanning roughly the given distance from its star (ls).
+    /// Belts inside the frost line are rocky or metallic, while those beyond it are mostly icy.
+    pub fn random<R: Rng + ?Sized>(name: String, distance: f64, frost_line: f64, rng: &mut R) -> Self {
+        let weights: [f64; 4] = if distance < frost_line {
+            [1.0, 50.0, 30.0, 19.0]
+        }else {
+            [80.0, 15.0, 4.0, 1.0]
+        };
+        let index: WeightedIndex<f64> = WeightedIndex::new(weights).expect("AsteroidBelt class weights are valid");
+        let class: RingClass = RING_CLASSES[index.sample(rng)];
+
+        let half_width: f64 = rng.gen_range(1.1..=1.5);
+        AsteroidBelt {
+            name,
+            class,
+            inner_radius: distance / half_width,
+            outer_radius: distance * half_width,
+            mass: log_uniform(1e9, 1e13, rng),
+        }
+    }
+
+    /// Returns the radial width of the *AsteroidBelt* in light-seconds.
+    pub fn width(&self) -> f64 {
+        self.outer_radius - self.inner_radius
+    }
+}
@@ -0,0 +1,62 @@
+use rand::Rng;
+
+
+/// Uncompressed density of water ice in grams per cubic centimeter.
+pub const ICE_DENSITY: f64 = 0.94;
+
+/// Uncompressed density of silicate rock in grams per cubic centimeter.
+pub const ROCK_DENSITY: f64 = 3.3;
+
+/// Uncompressed density of iron-nickel metal in grams per cubic centimeter.
+pub const METAL_DENSITY: f64 = 7.9;
+
+/// The bulk composition of the Earth, which the mass–radius relationship is calibrated against.
+pub const EARTH_COMPOSITION: Composition = Composition { ice: 0.0, rock: 67.5, metal: 32.5 };
+
+
+/// The bulk composition of a solid body, as percentages of its mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Composition {
+    pub ice: f64, // Percent
+    pub rock: f64, // Percent
+    pub metal: f64, // Percent
+}
+
+impl Composition {
+    /// Returns a randomly-drawn *Composition* for a solid planet of the given type identifier.
+    /// Metal-rich bodies are mostly metal, icy bodies mostly ice, and water and ammonia worlds carry deep oceans of volatiles.
+    /// Falls back to an Earth-like mix for any other identifier.
+    pub fn random<R: Rng + ?Sized>(planet_type: &str, rng: &mut R) -> Self {
+        let ((min_ice, max_ice), (min_metal, max_metal)): ((f64, f64), (f64, f64)) = match planet_type {
+            "MRB" => ((0.0, 0.0), (60.0, 90.0)),
+            "HMC" => ((0.0, 5.0), (30.0, 60.0)),
+            "RKB" => ((0.0, 10.0), (5.0, 30.0)),
+            "ELW" => ((0.0, 5.0), (25.0, 40.0)),
+            "WW" => ((20.0, 50.0), (10.0, 30.0)),
+            "AW" => ((15.0, 40.0), (10.0, 30.0)),
+            "RIW" => ((20.0, 50.0), (5.0, 20.0)),
+            "ICB" => ((60.0, 95.0), (0.0, 10.0)),
+            _ => return EARTH_COMPOSITION,
+        };
+        let ice: f64 = rng.gen_range(min_ice..=max_ice);
+        let metal: f64 = rng.gen_range(min_metal..=max_metal).min(100.0 - ice);
+
+        Composition {
+            ice,
+            rock: 100.0 - ice - metal,
+            metal,
+        }
+    }
+
+    /// Returns the uncompressed density of the mixture in grams per cubic centimeter.
+    pub fn density(&self) -> f64 {
+        100.0 / (self.ice / ICE_DENSITY + self.rock / ROCK_DENSITY + self.metal / METAL_DENSITY)
+    }
+
+    /// Returns how much larger a body of this composition is than an Earth-like body of the same mass.
+    /// Follows from equal masses taking up volumes in inverse proportion to their density.
+    pub fn radius_factor(&self) -> f64 {
+        (EARTH_COMPOSITION.density() / self.density()).cbrt()
+    }
+}
@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+use rand::{seq::SliceRandom, Rng};
+use crate::bodies::atmospheres::{AtmosphereDensity, AtmosphereType};
+use crate::bodies::planets::PlanetTypeProperties;
+use crate::bodies::stars::Star;
+use crate::bodies::volcanism::{Volcanism, VolcanismType};
+
+
+/// Value multiplier for being the first commander to log a species.
+pub const FIRST_LOGGED_MULTIPLIER: u64 = 5;
+
+/// Most distinct genera a single body can host.
+pub const MAX_GENERA: usize = 4;
+
+
+/// The volcanic activity a species needs from the body it grows on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolcanismRequirement {
+    Any, // Grows on active and dead bodies alike
+    Active, // Needs volcanism of any kind
+    Types(&'static [VolcanismType]), // Needs volcanism erupting one of these materials
+}
+
+impl VolcanismRequirement {
+    /// Returns true if a body with the given *Volcanism* (or *None* if geologically dead) meets the requirement.
+    pub fn is_met_by(&self, volcanism: Option<&Volcanism>) -> bool {
+        match (self, volcanism) {
+            (VolcanismRequirement::Any, _) => true,
+            (VolcanismRequirement::Active, volcanism) => volcanism.is_some(),
+            (VolcanismRequirement::Types(types), Some(volcanism)) => types.contains(&volcanism.volcanism_type),
+            (VolcanismRequirement::Types(_), None) => false,
+        }
+    }
+}
+
+
+/// A catalogued species and the environment it needs to survive.
+/// Every species needs a thin atmosphere on a body fit for on-foot exploration, on top of its own requirements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeciesInfo {
+    pub genus: &'static str,
+    pub name: &'static str, // Genus and species, e.g. "Bacterium Aurasus"
+    pub value: u64, // Credits
+    pub atmospheres: &'static [AtmosphereType], // Empty for any thin atmosphere
+    pub temperature: (f64, f64), // Kelvin
+    pub max_gravity: f64, // Factors of Earth's Gravity
+    pub star_classes: &'static [&'static str], // Labels of the parent star's class, empty for any star
+    pub volcanism: VolcanismRequirement,
+}
+
+impl SpeciesInfo {
+    /// Returns true if the species could grow on a body with the given properties orbiting the given *Star*.
+    pub fn can_live_on(&self, info: &PlanetTypeProperties, star: &Star) -> bool {
+        let Some(atmosphere) = &info.atmosphere else {
+            return false;
+        };
+        info.explorable
+            && atmosphere.density == AtmosphereDensity::Thin
+            && (self.atmospheres.is_empty() || self.atmospheres.contains(&atmosphere.atmosphere_type))
+            && (self.temperature.0..=self.temperature.1).contains(&info.surface_temp)
+            && info.gravity <= self.max_gravity
+            && (self.star_classes.is_empty() || self.star_classes.contains(&star.class.info().type_label.as_ref()))
+            && self.volcanism.is_met_by(info.volcanism.as_ref())
+    }
+
+    /// Returns the *Species* logged when a sample of this species is analysed.
+    pub fn to_species(&self) -> Species {
+        Species {
+            genus: self.genus.into(),
+            name: self.name.into(),
+            value: self.value,
+        }
+    }
+}
+
+/// Every catalogued species, grouped by genus.
+pub const SPECIES: [SpeciesInfo; 24] = [
+    SpeciesInfo { genus: "Aleoida", name: "Aleoida Arcus", value: 7_252_500, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (175.0, 180.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Aleoida", name: "Aleoida Coronamus", value: 6_284_600, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (180.0, 190.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Aleoida", name: "Aleoida Gravis", value: 12_934_900, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (190.0, 195.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Aurasus", value: 1_000_000, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (145.0, 400.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Nebulus", value: 5_289_900, atmospheres: &[AtmosphereType::Helium],
+        temperature: (20.0, 150.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Acies", value: 1_000_000, atmospheres: &[AtmosphereType::Neon],
+        temperature: (20.0, 60.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Vesicula", value: 1_000_000, atmospheres: &[AtmosphereType::Argon],
+        temperature: (50.0, 250.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Alcyoneum", value: 1_658_500, atmospheres: &[AtmosphereType::Ammonia],
+        temperature: (150.0, 400.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Cerbrus", value: 1_689_800,
+        atmospheres: &[AtmosphereType::Water, AtmosphereType::SulphurDioxide],
+        temperature: (130.0, 500.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Informem", value: 8_418_000, atmospheres: &[AtmosphereType::Nitrogen],
+        temperature: (40.0, 200.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Bacterium", name: "Bacterium Tela", value: 1_949_000, atmospheres: &[],
+        temperature: (0.0, 400.0), max_gravity: 0.6, star_classes: &[], volcanism: VolcanismRequirement::Active },
+    SpeciesInfo { genus: "Cactoida", name: "Cactoida Cortexum", value: 3_667_600, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (180.0, 195.0), max_gravity: 0.27, star_classes: &["A", "F", "G", "K", "M"], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Clypeus", name: "Clypeus Lacrimam", value: 8_418_000,
+        atmospheres: &[AtmosphereType::Water, AtmosphereType::CarbonDioxide],
+        temperature: (190.0, 400.0), max_gravity: 0.27, star_classes: &["B", "A", "F", "G", "K", "M"],
+        volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Concha", name: "Concha Renibus", value: 4_572_400,
+        atmospheres: &[AtmosphereType::Water, AtmosphereType::CarbonDioxide],
+        temperature: (180.0, 400.0), max_gravity: 0.27, star_classes: &[],
+        volcanism: VolcanismRequirement::Types(&[VolcanismType::Silicate, VolcanismType::Metallic]) },
+    SpeciesInfo { genus: "Electricae", name: "Electricae Pluma", value: 6_284_600,
+        atmospheres: &[AtmosphereType::Neon, AtmosphereType::Argon],
+        temperature: (20.0, 150.0), max_gravity: 0.27,
+        star_classes: &["A", "NS", "D", "DA", "DAB", "DAV", "DAZ", "DB", "DBV", "DBZ", "DC", "DCV", "DQ"],
+        volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Fonticulua", name: "Fonticulua Campestris", value: 1_000_000, atmospheres: &[AtmosphereType::Argon],
+        temperature: (50.0, 150.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Frutexa", name: "Frutexa Flabellum", value: 1_808_900, atmospheres: &[AtmosphereType::Ammonia],
+        temperature: (150.0, 400.0), max_gravity: 0.27, star_classes: &["F", "G", "K"], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Fumerata", name: "Fumerata Aegis", value: 3_626_400, atmospheres: &[],
+        temperature: (0.0, 400.0), max_gravity: 0.27, star_classes: &[],
+        volcanism: VolcanismRequirement::Types(&[
+            VolcanismType::Water, VolcanismType::Ammonia, VolcanismType::Methane, VolcanismType::CarbonDioxide,
+        ]) },
+    SpeciesInfo { genus: "Fungoida", name: "Fungoida Setisis", value: 1_670_100,
+        atmospheres: &[AtmosphereType::Ammonia, AtmosphereType::Methane],
+        temperature: (0.0, 400.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Osseus", name: "Osseus Fractus", value: 4_027_800, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (180.0, 190.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Recepta", name: "Recepta Umbrux", value: 12_934_900, atmospheres: &[AtmosphereType::SulphurDioxide],
+        temperature: (0.0, 400.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Stratum", name: "Stratum Tectonicas", value: 19_010_800, atmospheres: &[],
+        temperature: (165.0, 400.0), max_gravity: 4.2, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Tubus", name: "Tubus Conifer", value: 2_415_500, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (160.0, 190.0), max_gravity: 0.15, star_classes: &[], volcanism: VolcanismRequirement::Any },
+    SpeciesInfo { genus: "Tussock", name: "Tussock Pennata", value: 5_853_800, atmospheres: &[AtmosphereType::CarbonDioxide],
+        temperature: (145.0, 155.0), max_gravity: 0.27, star_classes: &[], volcanism: VolcanismRequirement::Any },
+];
+
+
+/// A species whose biological signal was found on a planet, e.g. "Bacterium Aurasus".
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Species {
+    pub genus: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    pub value: u64, // Credits
+}
+
+impl Species {
+    /// Returns the credit value of analysing the *Species* before any other commander.
+    pub fn first_logged_value(&self) -> u64 {
+        self.value * FIRST_LOGGED_MULTIPLIER
+    }
+}
+
+
+/* Exobiology Util Functions */
+
+/// Returns the species growing on a body with the given properties orbiting the given *Star*, sorted by name.
+/// Up to *MAX_GENERA* genera able to survive there are drawn, each represented by one of its species that can,
+/// so bodies without a thin atmosphere or too harsh to explore on foot are always lifeless.
+pub fn random_species<R: Rng + ?Sized>(info: &PlanetTypeProperties, star: &Star, rng: &mut R) -> Vec<Species> {
+    let candidates: Vec<&SpeciesInfo> = SPECIES.iter()
+        .filter(|species| species.can_live_on(info, star))
+        .collect();
+
+    let mut genera: Vec<&'static str> = candidates.iter().map(|species| species.genus).collect();
+    genera.dedup();
+    let count: usize = rng.gen_range(0..=genera.len().min(MAX_GENERA));
+
+    let genera: Vec<&'static str> = genera.choose_multiple(rng, count).copied().collect();
+
+    let mut species: Vec<Species> = genera.iter()
+        .filter_map(|genus| {
+            let members: Vec<&&SpeciesInfo> = candidates.iter().filter(|species| species.genus == *genus).collect();
+            members.choose(rng).map(|species| species.to_species())
+        })
+        .collect();
+    species.sort_by(|a, b| a.name.cmp(&b.name));
+    species
+}
@@ -0,0 +1,165 @@
+use rand::Rng;
+use crate::bodies::composition::Composition;
+
+
+/// A raw material that can be mined from the surface of a landable body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Material {
+    Carbon,
+    Iron,
+    Nickel,
+    Phosphorus,
+    Sulphur,
+    Chromium,
+    Germanium,
+    Manganese,
+    Vanadium,
+    Zinc,
+    Zirconium,
+    Arsenic,
+    Cadmium,
+    Mercury,
+    Molybdenum,
+    Niobium,
+    Selenium,
+    Tin,
+    Tungsten,
+    Antimony,
+    Polonium,
+    Ruthenium,
+    Technetium,
+    Tellurium,
+    Yttrium,
+}
+
+/// Every *Material*, from most to least common grade.
+pub const MATERIALS: [Material; 25] = [
+    Material::Carbon, Material::Iron, Material::Nickel, Material::Phosphorus, Material::Sulphur,
+    Material::Chromium, Material::Germanium, Material::Manganese, Material::Vanadium, Material::Zinc, Material::Zirconium,
+    Material::Arsenic, Material::Cadmium, Material::Mercury, Material::Molybdenum, Material::Niobium, Material::Selenium,
+    Material::Tin, Material::Tungsten,
+    Material::Antimony, Material::Polonium, Material::Ruthenium, Material::Technetium, Material::Tellurium, Material::Yttrium,
+];
+
+/// The part of a body a *Material* is mostly found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MaterialHost {
+    Metal, // Siderophile elements, which sink with iron
+    Rock, // Lithophile elements, bound into silicates
+    Volatile, // Elements that gather with ices and frozen gases
+}
+
+impl Material {
+    /// Returns a formatted *&str* of the *Material*, e.g. "Polonium".
+    pub fn fetch_name(&self) -> &'static str {
+        match self {
+            Material::Carbon => "Carbon",
+            Material::Iron => "Iron",
+            Material::Nickel => "Nickel",
+            Material::Phosphorus => "Phosphorus",
+            Material::Sulphur => "Sulphur",
+            Material::Chromium => "Chromium",
+            Material::Germanium => "Germanium",
+            Material::Manganese => "Manganese",
+            Material::Vanadium => "Vanadium",
+            Material::Zinc => "Zinc",
+            Material::Zirconium => "Zirconium",
+            Material::Arsenic => "Arsenic",
+            Material::Cadmium => "Cadmium",
+            Material::Mercury => "Mercury",
+            Material::Molybdenum => "Molybdenum",
+            Material::Niobium => "Niobium",
+            Material::Selenium => "Selenium",
+            Material::Tin => "Tin",
+            Material::Tungsten => "Tungsten",
+            Material::Antimony => "Antimony",
+            Material::Polonium => "Polonium",
+            Material::Ruthenium => "Ruthenium",
+            Material::Technetium => "Technetium",
+            Material::Tellurium => "Tellurium",
+            Material::Yttrium => "Yttrium",
+        }
+    }
+
+    /// Returns the rarity grade of the *Material*, from 1 (very common) to 4 (very rare).
+    pub fn grade(&self) -> u8 {
+        match self {
+            Material::Carbon | Material::Iron | Material::Nickel | Material::Phosphorus | Material::Sulphur => 1,
+            Material::Chromium | Material::Germanium | Material::Manganese | Material::Vanadium | Material::Zinc
+            | Material::Zirconium => 2,
+            Material::Arsenic | Material::Cadmium | Material::Mercury | Material::Molybdenum | Material::Niobium
+            | Material::Selenium | Material::Tin | Material::Tungsten => 3,
+            Material::Antimony | Material::Polonium | Material::Ruthenium | Material::Technetium | Material::Tellurium
+            | Material::Yttrium => 4,
+        }
+    }
+
+    /// Returns the part of a body the *Material* is mostly found in.
+    pub fn host(&self) -> MaterialHost {
+        match self {
+            Material::Iron | Material::Nickel | Material::Chromium | Material::Manganese | Material::Vanadium
+            | Material::Molybdenum | Material::Tungsten | Material::Ruthenium | Material::Technetium
+            | Material::Tin => MaterialHost::Metal,
+            Material::Zinc | Material::Zirconium | Material::Niobium | Material::Germanium | Material::Yttrium
+            | Material::Polonium => MaterialHost::Rock,
+            Material::Carbon | Material::Phosphorus | Material::Sulphur | Material::Arsenic | Material::Cadmium
+            | Material::Mercury | Material::Selenium | Material::Antimony | Material::Tellurium => MaterialHost::Volatile,
+        }
+    }
+}
+
+
+/* Material Util Functions */
+
+/// Returns the surface materials of a landable body of the given type identifier and bulk *Composition*,
+/// as percentages summing to 100, largest first.
+/// Each material's abundance follows its grade and how much of the body is made of its host (metal, rock or ice),
+/// while high metal content and metal-rich bodies are especially good hunting grounds for rare metals.
+pub fn random_materials<R: Rng + ?Sized>(planet_type: &str, composition: &Composition, rng: &mut R) -> Vec<(Material, f64)> {
+    let rare_metal_bonus: f64 = match planet_type {
+        "MRB" => 2.0,
+        "HMC" => 1.5,
+        _ => 1.0,
+    };
+
+    let mut weights: Vec<(Material, f64)> = MATERIALS.iter()
+        .map(|material| {
+            let host: f64 = match material.host() {
+                MaterialHost::Metal => composition.metal,
+                MaterialHost::Rock => composition.rock,
+                MaterialHost::Volatile => composition.ice + 10.0,
+            };
+            let grade: f64 = match material.grade() {
+                1 => 20.0,
+                2 => 6.0,
+                3 => 2.0,
+                _ => 0.5,
+            };
+            let bonus: f64 = if material.host() == MaterialHost::Metal && material.grade() >= 3 { rare_metal_bonus } else { 1.0 };
+            (*material, (host + 1.0) * grade * bonus)
+        })
+        .collect();
+
+    // Draw distinct materials without replacement, each with probability proportional to its weight.
+    let count: usize = rng.gen_range(6..=10);
+    let mut materials: Vec<(Material, f64)> = Vec::with_capacity(count);
+    for _ in 0..count {
+        let total: f64 = weights.iter().map(|(_, w)| w).sum();
+        let mut pick: f64 = rng.gen_range(0.0..total);
+        let index: usize = weights.iter()
+            .position(|(_, w)| {
+                pick -= w;
+                pick < 0.0
+            })
+            .unwrap_or(weights.len() - 1);
+        let (material, weight) = weights.swap_remove(index);
+        materials.push((material, weight * rng.gen_range(0.5..=1.5)));
+    };
+
+    let total: f64 = materials.iter().map(|(_, w)| w).sum();
+    materials.iter_mut().for_each(|(_, share)| *share *= 100.0 / total);
+    materials.sort_by(|a, b| b.1.total_cmp(&a.1));
+    materials
+}
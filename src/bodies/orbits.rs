@@ -0,0 +1,219 @@
+use std::f64::consts::{PI, TAU};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Newtonian constant of gravitation (m³ kg⁻¹ s⁻²).
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.674_30e-11;
+
+/// Mass of the Sun in kilograms.
+pub const SOLAR_MASS_KG: f64 = 1.988_47e30;
+
+/// Number of Earth masses in one solar mass.
+pub const EARTH_MASSES_PER_SOLAR_MASS: f64 = 332_946.048_7;
+
+/// Distance travelled by light in one second, in meters.
+pub const LIGHT_SECOND_M: f64 = 299_792_458.0;
+
+
+/// A position or velocity in a system's reference frame.
+/// Positions are measured in light-seconds and velocities in kilometers per second.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vector3 {
+    /// Returns a new instance of the *Vector3* struct.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    /// Returns the length of the vector.
+    pub fn magnitude(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Returns the distance between two positions.
+    pub fn distance(&self, other: &Vector3) -> f64 {
+        (*self - *other).magnitude()
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, scale: f64) -> Vector3 {
+        Vector3::new(self.x * scale, self.y * scale, self.z * scale)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        self * -1.0
+    }
+}
+
+
+/// The six classical Keplerian elements of an orbit, plus the mass it is bound by.
+/// Angles are stored in degrees, distances in light-seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrbitalElements {
+    pub semi_major_axis: f64, // Light-Seconds
+    pub eccentricity: f64,
+    pub inclination: f64, // Degrees
+    pub arg_of_periapsis: f64, // Degrees
+    pub ascending_node: f64, // Degrees, longitude of
+    pub mean_anomaly: f64, // Degrees, at epoch (t = 0)
+    pub system_mass: f64, // Solar masses, parent plus orbiting body
+}
+
+impl OrbitalElements {
+    /// Returns a new instance of the *OrbitalElements* struct with randomly-drawn shape and orientation.
+    /// Most orbits are near-circular and near the reference plane, with the occasional eccentric or inclined outlier.
+    pub fn random<R: Rng + ?Sized>(semi_major_axis: f64, system_mass: f64, rng: &mut R) -> Self {
+        let eccentricity: f64 = rng.gen::<f64>().powi(3) * 0.6;
+        let inclination: f64 = if rng.gen_bool(1.0 / 10.0) {
+            rng.gen_range(5.0..=90.0)
+        }else {
+            rng.gen_range(0.0..5.0)
+        };
+
+        OrbitalElements {
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            arg_of_periapsis: rng.gen_range(0.0..360.0),
+            ascending_node: rng.gen_range(0.0..360.0),
+            mean_anomaly: rng.gen_range(0.0..360.0),
+            system_mass,
+        }
+    }
+
+    /// Returns the standard gravitational parameter (μ = GM) of the orbit in m³/s².
+    pub fn gravitational_parameter(&self) -> f64 {
+        GRAVITATIONAL_CONSTANT * self.system_mass * SOLAR_MASS_KG
+    }
+
+    /// Returns the orbital period from Kepler's third law: T = 2π √(a³ / GM).
+    pub fn period(&self) -> Duration {
+        let a: f64 = self.semi_major_axis * LIGHT_SECOND_M;
+        Duration::from_secs_f64(TAU * (a.powi(3) / self.gravitational_parameter()).sqrt())
+    }
+
+    /// Returns the closest approach to the parent (periapsis) in light-seconds.
+    pub fn periapsis(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.eccentricity)
+    }
+
+    /// Returns the furthest distance from the parent (apoapsis) in light-seconds.
+    pub fn apoapsis(&self) -> f64 {
+        self.semi_major_axis * (1.0 + self.eccentricity)
+    }
+
+    /// Returns the orbit of one member of a pair about their shared barycentre, where the calling orbit describes
+    /// the secondary relative to the primary. *other_mass_fraction* is the share of the pair's mass held by the other member.
+    /// Both members keep the relative orbit's period, with the primary always on the opposite side of the barycentre.
+    pub fn about_barycentre(&self, other_mass_fraction: f64, is_primary: bool) -> OrbitalElements {
+        let arg_of_periapsis: f64 = if is_primary {
+            (self.arg_of_periapsis + 180.0) % 360.0
+        }else {
+            self.arg_of_periapsis
+        };
+
+        OrbitalElements {
+            semi_major_axis: self.semi_major_axis * other_mass_fraction,
+            arg_of_periapsis,
+            system_mass: self.system_mass * other_mass_fraction.powi(3),
+            ..*self
+        }
+    }
+
+    /// Returns the mean anomaly in radians at *t* seconds after epoch (negative *t* looks into the past).
+    pub fn mean_anomaly_at(&self, t: f64) -> f64 {
+        let a: f64 = self.semi_major_axis * LIGHT_SECOND_M;
+        let mean_motion: f64 = (self.gravitational_parameter() / a.powi(3)).sqrt();
+        (self.mean_anomaly.to_radians() + mean_motion * t).rem_euclid(TAU)
+    }
+
+    /// Returns the eccentric anomaly in radians at *t* seconds after epoch,
+    /// solving Kepler's equation (M = E - e·sin E) with Newton's method.
+    pub fn eccentric_anomaly_at(&self, t: f64) -> f64 {
+        let mean_anomaly: f64 = self.mean_anomaly_at(t);
+        let e: f64 = self.eccentricity;
+        let mut anomaly: f64 = if e < 0.8 { mean_anomaly } else { PI };
+        for _ in 0..50 {
+            let delta: f64 = (anomaly - e * anomaly.sin() - mean_anomaly) / (1.0 - e * anomaly.cos());
+            anomaly -= delta;
+            if delta.abs() < 1e-12 {
+                break;
+            };
+        };
+        anomaly
+    }
+
+    /// Returns the position relative to the parent, in light-seconds, at *t* seconds after epoch.
+    pub fn position_at(&self, t: f64) -> Vector3 {
+        let e: f64 = self.eccentricity;
+        let anomaly: f64 = self.eccentric_anomaly_at(t);
+        let x: f64 = self.semi_major_axis * (anomaly.cos() - e);
+        let y: f64 = self.semi_major_axis * (1.0 - e * e).sqrt() * anomaly.sin();
+        self.rotate_into_frame(x, y)
+    }
+
+    /// Returns the velocity relative to the parent, in kilometers per second, at *t* seconds after epoch.
+    pub fn velocity_at(&self, t: f64) -> Vector3 {
+        let e: f64 = self.eccentricity;
+        let anomaly: f64 = self.eccentric_anomaly_at(t);
+        let a: f64 = self.semi_major_axis * LIGHT_SECOND_M;
+        let r: f64 = a * (1.0 - e * anomaly.cos());
+        let scale: f64 = (self.gravitational_parameter() * a).sqrt() / r / 1_000.0;
+        let vx: f64 = -scale * anomaly.sin();
+        let vy: f64 = scale * (1.0 - e * e).sqrt() * anomaly.cos();
+        self.rotate_into_frame(vx, vy)
+    }
+
+    /// Rotates a vector from the orbital plane (periapsis along x) into the system's reference frame.
+    fn rotate_into_frame(&self, x: f64, y: f64) -> Vector3 {
+        let (sin_w, cos_w): (f64, f64) = self.arg_of_periapsis.to_radians().sin_cos();
+        let (sin_i, cos_i): (f64, f64) = self.inclination.to_radians().sin_cos();
+        let (sin_o, cos_o): (f64, f64) = self.ascending_node.to_radians().sin_cos();
+
+        Vector3 {
+            x: (cos_o * cos_w - sin_o * sin_w * cos_i) * x + (-cos_o * sin_w - sin_o * cos_w * cos_i) * y,
+            y: (sin_o * cos_w + cos_o * sin_w * cos_i) * x + (-sin_o * sin_w + cos_o * cos_w * cos_i) * y,
+            z: (sin_w * sin_i) * x + (cos_w * sin_i) * y,
+        }
+    }
+}
+
+
+/* Orbital Util Functions */
+
+/// Returns a random value between min and max, evenly distributed in logarithmic space.
+pub(crate) fn log_uniform<R: Rng + ?Sized>(min: f64, max: f64, rng: &mut R) -> f64 {
+    rng.gen_range(min.ln()..=max.ln()).exp().clamp(min, max)
+}
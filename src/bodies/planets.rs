@@ -1,103 +1,304 @@
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::OnceLock;
 use std::time::Duration;
-use crate::Rarity;
+use crate::{ParseError, Rarity};
+use crate::bodies::stars::{Star, StarClass, AU_LS, SOLAR_RADIUS_LS};
+use crate::bodies::rings::{RingSystem, roche_limit};
+use crate::bodies::orbits::{OrbitalElements, Vector3, log_uniform, EARTH_MASSES_PER_SOLAR_MASS, LIGHT_SECOND_M};
+use crate::bodies::scans::{ScanOptions, planet_scan_value};
+use crate::bodies::atmospheres::Atmosphere;
+use crate::bodies::volcanism::Volcanism;
+use crate::bodies::composition::Composition;
+use crate::bodies::materials::{Material, random_materials};
+use crate::bodies::exobiology::{Species, random_species};
 use rand::{
-    distributions::{Distribution, Standard},
+    distributions::{Distribution, Standard, WeightedError, WeightedIndex},
+    rngs::StdRng,
     Rng,
+    SeedableRng,
     thread_rng as rng,
 };
 
 
-#[derive(Debug)]
-pub struct PlanetTypeProperties<'a> {
-    pub type_label: &'a str,
-    pub type_name: &'a str,
-    pub description: &'a str,
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlanetTypeProperties {
+    pub type_label: Cow<'static, str>,
+    pub type_name: Cow<'static, str>,
+    pub description: Cow<'static, str>,
     pub rarity: Rarity,
-    pub ringed: bool,
+    pub rings: Option<RingSystem>, // None for bodies without rings
     pub landable: bool,
     pub explorable: bool,
-    pub dist_from_arrival: f64, // Light-Seconds
+    pub terraform_state: TerraformState,
+    pub dist_from_arrival: f64, // Light-Seconds, 0 until placed around a star
     pub surface_temp: f64, // Kelvin
     pub surface_pressure: f64, // Factors of Earth's Atmosphere
+    pub atmosphere: Option<Atmosphere>, // None for airless bodies
+    pub volcanism: Option<Volcanism>, // None for geologically dead bodies
     pub radius: f64, // Kilometers
     pub earth_masses: f64,
+    pub composition: Option<Composition>, // None for gas giants
+    pub materials: Vec<(Material, f64)>, // Percentages summing to 100, empty unless landable
+    pub species: Vec<Species>, // Sorted by name, empty for lifeless bodies
     pub gravity: f64, // Factors of Earth's Gravity
-    pub orbital_period: Duration,
+    pub orbital_period: Duration, // Zero until placed in an orbit
     pub rotational_period: Duration,
+    pub orbit: Option<OrbitalElements>, // None for bodies that orbit nothing
 }
 
-#[derive(Debug)]
-pub struct Stats<'a> {
-    pub label: &'a str,
-    pub type_name: &'a str,
-    pub description: &'a str,
-    pub rarity: &'a str,
-    pub ringed: bool,
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    pub label: Cow<'static, str>,
+    pub type_name: Cow<'static, str>,
+    pub description: Cow<'static, str>,
+    pub rarity: Cow<'static, str>,
+    pub rings: Option<RingSystem>,
     pub landable: bool,
     pub explorable: bool,
+    pub terraform_state: TerraformState,
     pub dist_from_arrival: f64,
     pub surface_temp: f64,
     pub surface_pressure: f64,
+    pub atmosphere: Option<Atmosphere>,
+    pub volcanism: Option<Volcanism>,
     pub radius: f64,
     pub earth_masses: f64,
+    pub composition: Option<Composition>,
+    pub materials: Vec<(Material, f64)>,
+    pub species: Vec<Species>,
     pub gravity: f64,
     pub orbital_period: Duration,
     pub rotational_period: Duration,
+    pub orbit: Option<OrbitalElements>,
 }
 
-#[derive(Debug)]
-pub enum PlanetType<'a> {
-    AW(PlanetTypeProperties<'a>),
-    WW(PlanetTypeProperties<'a>),
-    WG(PlanetTypeProperties<'a>),
-    RKB(PlanetTypeProperties<'a>),
-    ICB(PlanetTypeProperties<'a>),
-    ELW(PlanetTypeProperties<'a>),
-    HMC(PlanetTypeProperties<'a>),
-    RIW(PlanetTypeProperties<'a>),
-    MRB(PlanetTypeProperties<'a>),
-    HGG(PlanetTypeProperties<'a>),
-    GGGG(PlanetTypeProperties<'a>),
-    CIGG(PlanetTypeProperties<'a>),
-    CIIGG(PlanetTypeProperties<'a>),
-    CIIIGG(PlanetTypeProperties<'a>),
-    CIVGG(PlanetTypeProperties<'a>),
-    CVGG(PlanetTypeProperties<'a>),
-    HRGG(PlanetTypeProperties<'a>),
-    GGWABL(PlanetTypeProperties<'a>),
-    GGWWBL(PlanetTypeProperties<'a>),
+/// How far along a planet is in being terraformed into an Earth-like world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TerraformState {
+    NotTerraformable,
+    Candidate,
+    Terraforming,
+    Terraformed,
 }
 
-impl<'a> Distribution<PlanetType<'a>> for Standard {
-
-    // TODO: Implement random PlanetType picker weighted by rarity instead of linear rng
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PlanetType<'a> {
-        match rng.gen_range(0..=18) {
-            0 => PlanetType::new("AW"),
-            1 => PlanetType::new("WW"),
-            2 => PlanetType::new("WG"),
-            3 => PlanetType::new("RKB"),
-            4 => PlanetType::new("ICB"),
-            5 => PlanetType::new("ELW"),
-            6 => PlanetType::new("HMC"),
-            7 => PlanetType::new("RIW"),
-            8 => PlanetType::new("MRB"),
-            9 => PlanetType::new("HGG"),
-            10 => PlanetType::new("GGGG"),
-            11 => PlanetType::new("CIGG"),
-            12 => PlanetType::new("CIIGG"),
-            13 => PlanetType::new("CIIIGG"),
-            14 => PlanetType::new("CIVGG"),
-            15 => PlanetType::new("CVGG"),
-            16 => PlanetType::new("HRGG"),
-            17 => PlanetType::new("GGWABL"),
-            18 => PlanetType::new("GGWWBL"),
-            _ => panic!("Invalid PlanetType!"),
+impl TerraformState {
+    /// Returns a randomly-drawn *TerraformState* for a planet of the given type identifier and surface conditions.
+    /// Only water worlds, high metal content, rocky and metal-rich bodies in a temperate band with bearable gravity
+    /// can be terraformed, thicker atmospheres make it less likely, and only a few candidates are already being worked on.
+    pub fn random<R: Rng + ?Sized>(planet_type: &str, surface_temp: f64, surface_pressure: f64, gravity: f64, rng: &mut R) -> Self {
+        let chance: f64 = match planet_type {
+            "WW" => 0.6,
+            "HMC" => 0.35,
+            "RKB" => 0.25,
+            "MRB" => 0.1,
+            _ => return TerraformState::NotTerraformable,
+        };
+        let atmosphere_factor: f64 = (1.0 - (1.0 + surface_pressure.max(0.0)).log10() / 12.0).clamp(0.1, 1.0);
+        if !is_terraformable(surface_temp, gravity) || !rng.gen_bool(chance * atmosphere_factor) {
+            return TerraformState::NotTerraformable;
+        };
+
+        let progress: f64 = rng.gen();
+        if progress < 0.01 {
+            TerraformState::Terraformed
+        }else if progress < 0.03 {
+            TerraformState::Terraforming
+        }else {
+            TerraformState::Candidate
+        }
+    }
+
+    /// Returns a formatted *&str* of the *TerraformState*, e.g. "Terraformable".
+    pub fn fetch_state(&self) -> &'static str {
+        match self {
+            TerraformState::NotTerraformable => "Not Terraformable",
+            TerraformState::Candidate => "Terraformable",
+            TerraformState::Terraforming => "Terraforming",
+            TerraformState::Terraformed => "Terraformed",
+        }
+    }
+
+    /// Returns true for every state but *NotTerraformable*.
+    pub fn is_terraformable(&self) -> bool {
+        *self != TerraformState::NotTerraformable
+    }
+
+    /// Returns the type name prefixed with the state, e.g. "Terraformable High Metal Content Planet".
+    /// Planets that cannot be terraformed keep their type name unchanged.
+    pub fn prefix(&self, type_name: &'static str) -> Cow<'static, str> {
+        if self.is_terraformable() {
+            format!("{} {}", self.fetch_state(), type_name).into()
+        }else {
+            type_name.into()
         }
     }
 }
 
-impl<'a> PlanetType<'a> {
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlanetType {
+    AW(PlanetTypeProperties),
+    WW(PlanetTypeProperties),
+    WG(PlanetTypeProperties),
+    RKB(PlanetTypeProperties),
+    ICB(PlanetTypeProperties),
+    ELW(PlanetTypeProperties),
+    HMC(PlanetTypeProperties),
+    RIW(PlanetTypeProperties),
+    MRB(PlanetTypeProperties),
+    HGG(PlanetTypeProperties),
+    GGGG(PlanetTypeProperties),
+    CIGG(PlanetTypeProperties),
+    CIIGG(PlanetTypeProperties),
+    CIIIGG(PlanetTypeProperties),
+    CIVGG(PlanetTypeProperties),
+    CVGG(PlanetTypeProperties),
+    HRGG(PlanetTypeProperties),
+    GGWABL(PlanetTypeProperties),
+    GGWWBL(PlanetTypeProperties),
+}
+
+/// Mean radius of the Earth in kilometers.
+pub const EARTH_RADIUS_KM: f64 = 6_371.0;
+
+/// Mean density of the Earth in grams per cubic centimeter.
+pub const EARTH_DENSITY: f64 = 5.514;
+
+/// Equilibrium temperature in Kelvin of a body with Earth's albedo, 1 AU from the Sun.
+pub const EQUILIBRIUM_TEMP_AT_1_AU: f64 = 254.6;
+
+/// Fraction of a planet's Hill sphere within which moon orbits remain stable.
+pub const MOON_HILL_FRACTION: f64 = 0.5;
+
+/// Largest mass a moon may have, as a fraction of its parent planet's mass.
+pub const MAX_MOON_MASS_RATIO: f64 = 0.1;
+
+/// Identifiers of the solid *PlanetType*s that can form as moons.
+const MOON_TYPE_IDENTIFIERS: [&str; 8] = ["AW", "WW", "RKB", "ICB", "ELW", "HMC", "RIW", "MRB"];
+
+/// Every valid *PlanetType* identifier, as accepted by *PlanetType::try_new()* and *str::parse()*.
+pub const PLANET_TYPE_IDENTIFIERS: [&str; 19] = [
+    "AW", "WW", "WG", "RKB", "ICB", "ELW", "HMC", "RIW", "MRB", "HGG", "GGGG", "CIGG", "CIIGG", "CIIIGG", "CIVGG", "CVGG", "HRGG", "GGWABL", "GGWWBL",
+];
+
+/// Base *Rarity* tier of every *PlanetType* identifier.
+const PLANET_TYPE_RARITY: [(&str, Rarity); 19] = [
+    ("AW", Rarity::VeryRare),
+    ("WW", Rarity::Rare),
+    ("WG", Rarity::VeryRare),
+    ("RKB", Rarity::VeryCommon),
+    ("ICB", Rarity::VeryCommon),
+    ("ELW", Rarity::ExtremelyRare),
+    ("HMC", Rarity::Common),
+    ("RIW", Rarity::Uncommon),
+    ("MRB", Rarity::Uncommon),
+    ("HGG", Rarity::VeryRare),
+    ("GGGG", Rarity::Legendary),
+    ("CIGG", Rarity::Common),
+    ("CIIGG", Rarity::Rare),
+    ("CIIIGG", Rarity::Common),
+    ("CIVGG", Rarity::Uncommon),
+    ("CVGG", Rarity::Rare),
+    ("HRGG", Rarity::VeryRare),
+    ("GGWABL", Rarity::VeryRare),
+    ("GGWWBL", Rarity::Rare),
+];
+
+/// A weighted sampler over *PlanetType* identifiers.
+/// The default weights follow each type's *Rarity*, and can be conditioned on a parent *Star* with *for_star()*.
+#[derive(Debug, Clone)]
+pub struct PlanetTypeWeights {
+    weights: Vec<(&'static str, f64)>,
+    index: WeightedIndex<f64>,
+}
+
+impl PlanetTypeWeights {
+    /// Returns a new instance of the *PlanetTypeWeights* struct from a list of (identifier, weight) pairs.
+    /// Fails if the list is empty, any weight is negative or not finite, or every weight is zero.
+    pub fn new(weights: Vec<(&'static str, f64)>) -> Result<Self, WeightedError> {
+        if weights.iter().any(|(_, w)| !w.is_finite()) {
+            return Err(WeightedError::InvalidWeight);
+        };
+        let index: WeightedIndex<f64> = WeightedIndex::new(weights.iter().map(|(_, w)| *w))?;
+        Ok(PlanetTypeWeights { weights, index })
+    }
+
+    /// Returns the default rarity weights, scaled by how likely each type is to form around the given *Star*.
+    /// Stellar remnants rarely host Earth-like worlds, while cool stars favour ammonia worlds and icy bodies.
+    pub fn for_star(star: &Star) -> Self {
+        let weights: Vec<(&'static str, f64)> = PLANET_TYPE_RARITY
+            .iter()
+            .map(|(id, rarity)| (*id, rarity.weight() * star_affinity(&star.class, id)))
+            .collect();
+        Self::new(weights).expect("Star-conditioned PlanetType weights are valid")
+    }
+
+    /// Returns the (identifier, weight) pairs used by this sampler.
+    pub fn weights(&self) -> &[(&'static str, f64)] {
+        &self.weights
+    }
+
+    /// Returns the weight assigned to a type identifier, or *None* if the identifier is not in the table.
+    pub fn weight_of(&self, planet_type: &str) -> Option<f64> {
+        self.weights.iter().find(|(id, _)| *id == planet_type).map(|(_, w)| *w)
+    }
+
+    /// Returns a copy of these weights with the given type identifier's weight replaced (or added).
+    pub fn with_weight(&self, planet_type: &'static str, weight: f64) -> Result<Self, WeightedError> {
+        let mut weights: Vec<(&'static str, f64)> = self.weights.clone();
+        match weights.iter_mut().find(|(id, _)| *id == planet_type) {
+            Some(entry) => entry.1 = weight,
+            None => weights.push((planet_type, weight)),
+        };
+        Self::new(weights)
+    }
+
+    /// Returns the default rarity weights restricted to the solid types that can form as moons.
+    pub fn for_moons() -> Self {
+        let weights: Vec<(&'static str, f64)> = PLANET_TYPE_RARITY
+            .iter()
+            .filter(|(id, _)| MOON_TYPE_IDENTIFIERS.contains(id))
+            .map(|(id, rarity)| (*id, rarity.weight()))
+            .collect();
+        Self::new(weights).expect("Moon PlanetType weights are valid")
+    }
+
+    /// Returns a randomly-picked type identifier, with probability proportional to its weight.
+    pub fn sample_identifier<R: Rng + ?Sized>(&self, rng: &mut R) -> &'static str {
+        self.weights[self.index.sample(rng)].0
+    }
+}
+
+impl Default for PlanetTypeWeights {
+    fn default() -> Self {
+        let weights: Vec<(&'static str, f64)> = PLANET_TYPE_RARITY
+            .iter()
+            .map(|(id, rarity)| (*id, rarity.weight()))
+            .collect();
+        Self::new(weights).expect("Default PlanetType weights are valid")
+    }
+}
+
+impl Distribution<PlanetType> for PlanetTypeWeights {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PlanetType {
+        PlanetType::from_rng(self.sample_identifier(rng), rng)
+    }
+}
+
+/* Enables Fetching a random variant of the PlanetType enum, weighted by rarity */
+impl Distribution<PlanetType> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PlanetType {
+        static DEFAULT_WEIGHTS: OnceLock<PlanetTypeWeights> = OnceLock::new();
+        DEFAULT_WEIGHTS.get_or_init(PlanetTypeWeights::default).sample(rng)
+    }
+}
+
+impl PlanetType {
 
     /// Returns a new instance of the *PlanetType* enum with randomly-initialized values.
     /// Accepts a type identifier as a &str, but will return a random *PlanetType* if an invalid identifier is provided.
@@ -105,35 +306,49 @@ impl<'a> PlanetType<'a> {
     /// **Valid Identifiers:**
     /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
     pub fn new(planet_type: &str) -> Self {
+        Self::from_rng(planet_type, &mut rng())
+    }
+
+    /// Returns a new instance of the *PlanetType* enum, or a *ParseError* if the identifier is not recognised.
+    /// "random" is also accepted, and yields a random *PlanetType*.
+    /// 
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn try_new(planet_type: &str) -> Result<Self, ParseError> {
+        Self::try_from_rng(planet_type, &mut rng())
+    }
+
+    /// Returns a new instance of the *PlanetType* enum with values drawn from the provided *Rng*,
+    /// or a *ParseError* if the identifier is not recognised.
+    /// 
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn try_from_rng<R: Rng + ?Sized>(planet_type: &str, rng: &mut R) -> Result<Self, ParseError> {
+        if planet_type != "random" && !PLANET_TYPE_IDENTIFIERS.contains(&planet_type) {
+            return Err(ParseError::new("PlanetType", planet_type, &PLANET_TYPE_IDENTIFIERS));
+        };
+        Ok(Self::from_rng(planet_type, rng))
+    }
+
+    /// Returns a new instance of the *PlanetType* enum with values drawn from the provided *Rng*.
+    /// The same type identifier and *Rng* state will always yield identical values.
+    /// The body is unplaced, with a distance from arrival and orbital period of zero, until *Planet::place_around()* puts it in orbit of a *Star*.
+    /// 
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn from_rng<R: Rng + ?Sized>(planet_type: &str, rng: &mut R) -> Self {
 
         // TODO: Calculate stat numbers with a weighted bias towards the middle.
         // TODO: Calculate ring probability based on planet type.
         match planet_type {
             "AW" => { // Ammonia World
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Ammonia World";
-                    description = "Terrestrial ammonia world";
-                    type_label = "AW(R)";
-                }else {
-                    type_name = "Ammonia World";
-                    description = "TODO";
-                    type_label = "AW";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
                 let rarity: Rarity = Rarity::VeryRare;
                 
-                const MIN_DIST: f64 = 7.0;
-                const MAX_DIST: f64 = 81_7190.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 4_983_498.11683198;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 27.0;
@@ -142,75 +357,82 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 0.07346;
                 const MAX_MASSES: f64 = 1_327.610718;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 2_699.66775;
-                const MAX_RADIUS: f64 = 30_741.622;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let composition: Composition = Composition::random("AW", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("AW", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("AW", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.249672518138;
-                const MAX_GRAVITY: f64 = 91.80329702145;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.258450279086;
-                const MAX_ORBITAL: f64 = 747_992.070736713;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86_400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Ammonia World";
+                    description = "Terrestrial ammonia world";
+                    type_label = "AW(R)";
+                }else {
+                    type_name = "Ammonia World";
+                    description = "TODO";
+                    type_label = "AW";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.211842411736;
                 const MAX_ROTATIONAL: f64 = 4_442.4380215662;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("AW", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::AW(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::AW(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "WW" => { // Water World
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Water World";
-                    description = "TODO";
-                    type_label = "WW(R)";
-                }else {
-                    type_name = "Water World";
-                    description = "TODO";
-                    type_label = "WW";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
                 let rarity: Rarity = Rarity::Rare;
 
-                const MIN_DIST: f64 = 3.0;
-                const MAX_DIST: f64 = 4_217_470.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.07; 
                 const MAX_PRESSURE: f64 = 6_319_180.5;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 150.0;
@@ -219,75 +441,83 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 0.0687;
                 const MAX_MASSES: f64 = 741.438171;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 2_640.894;
-                const MAX_RADIUS: f64 = 29_011.342;
-                let radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let composition: Composition = Composition::random("WW", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let terraform_state: TerraformState = TerraformState::random("WW", surface_temp, surface_pressure, gravity, rng);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("WW", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("WW", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.250068247718;
-                const MAX_GRAVITY: f64 = 46.013426658406;
-                let gravity: f64 = rng().gen_range(MIN_GRAVITY..=MAX_GRAVITY);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.003370370512;
-                const MAX_ORBITAL: f64 = 570_992.687407407;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86_400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Water World";
+                    description = "TODO";
+                    type_label = "WW(R)";
+                }else {
+                    type_name = "Water World";
+                    description = "TODO";
+                    type_label = "WW";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.124829452037; // Tidal Egg
                 const MAX_ROTATIONAL: f64 = 71_900.1459814641;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("WW", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::WW(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::WW(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: terraform_state.prefix(type_name),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "WG" => { // Water Giant
-                let ringed: bool = rng().gen_bool(1.0 / 15.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Water Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "WG(R)";
-                }else {
-                    type_name = "Water Giant";
-                    description = "CHANGE ME";
-                    type_label = "WG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 15.0);
 
                 let rarity: Rarity = Rarity::VeryRare;
 
-                const MIN_DIST: f64 = 21.0;
-                const MAX_DIST: f64 = 690_129.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 1337.4206969;
                 const MAX_PRESSURE: f64 = 29_501_937_664.0;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 136.0;
@@ -296,75 +526,76 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 17.23122;
                 const MAX_MASSES: f64 = 1_961.928589;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 15_892.973;
-                const MAX_RADIUS: f64 = 30_942.572;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("WG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("WG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 2.298430556431;
-                const MAX_GRAVITY: f64 = 193.710734791427;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.780787489155;
-                const MAX_ORBITAL: f64 = 38_728.2255623079;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Water Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "WG(R)";
+                }else {
+                    type_name = "Water Giant";
+                    description = "CHANGE ME";
+                    type_label = "WG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.159246328854;
                 const MAX_ROTATIONAL: f64 = 3_489.59481481481;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::WG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::WG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "RKB" => { // Rocky Body
-                let ringed: bool = rng().gen_bool(1.0 / 20.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Rocky Body";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "RKB(R)";
-                }else {
-                    type_name = "Rocky Body";
-                    description = "CHANGE ME";
-                    type_label = "RKB";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 20.0);
 
                 let rarity: Rarity = Rarity::VeryCommon;
 
-                const MIN_DIST: f64 = 3.0;
-                const MAX_DIST: f64 = 7_492_300.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 2_516_369_920.0;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 20.0;
@@ -373,75 +604,83 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 0.0001;
                 const MAX_MASSES: f64 = 527.839539;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 181.887875;
-                const MAX_RADIUS: f64 = 21_765.112;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let composition: Composition = Composition::random("RKB", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let terraform_state: TerraformState = TerraformState::random("RKB", surface_temp, surface_pressure, gravity, rng);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("RKB", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("RKB", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.007895669291;
-                const MAX_GRAVITY: f64 = 50.039830862644;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.001000000046;
-                const MAX_ORBITAL: f64 = 12_163.6164409143;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Rocky Body";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "RKB(R)";
+                }else {
+                    type_name = "Rocky Body";
+                    description = "CHANGE ME";
+                    type_label = "RKB";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.100663452148;
                 const MAX_ROTATIONAL: f64 = 166_276.93037037;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("RKB", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::RKB(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::RKB(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: terraform_state.prefix(type_name),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "ICB" => { // Icy Body
-                let ringed: bool = rng().gen_bool(1.0 / 20.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Icy Body";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "ICB(R)";
-                }else {
-                    type_name = "Icy Body";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "ICB";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 20.0);
 
                 let rarity: Rarity = Rarity::VeryCommon;
 
-                const MIN_DIST: f64 = 1.37026;
-                const MAX_DIST: f64 = 15_653_000.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 204_413_011.941219;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 1.0;
@@ -450,75 +689,82 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 0.0001;
                 const MAX_MASSES: f64 = 2_214.019287;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 160.0;
-                const MAX_RADIUS: f64 = 31_232.91;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let composition: Composition = Composition::random("ICB", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("ICB", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("ICB", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.004758505708;
-                const MAX_GRAVITY: f64 = 236.648152852392;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.000104166667;
-                const MAX_ORBITAL: f64 = 1_257_206_278.81862;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Icy Body";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "ICB(R)";
+                }else {
+                    type_name = "Icy Body";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "ICB";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.081735393383;
                 const MAX_ROTATIONAL: f64 = 2_479_320.4190602;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("ICB", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::ICB(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::ICB(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "ELW" => { // Earth-like World
-                let ringed: bool = rng().gen_bool(1.0 / 420.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Earth-like World";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "ELW(R)";
-                }else {
-                    type_name = "Earth-like World";
-                    description = "CHANGE ME";
-                    type_label = "ELW";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 420.0);
 
                 let rarity: Rarity = Rarity::ExtremelyRare;
 
-                const MIN_DIST: f64 = 6.0;
-                const MAX_DIST: f64 = 736_306.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.24206969;
                 const MAX_PRESSURE: f64 = 7.291643844066;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 260.0;
@@ -527,75 +773,82 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 0.026;
                 const MAX_MASSES: f64 = 7.1;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 1_944.26225;
-                const MAX_RADIUS: f64 = 11_914.006;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let composition: Composition = Composition::random("ELW", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("ELW", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("ELW", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.279545410512;
-                const MAX_GRAVITY: f64 = 2.553103251365;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.279575634606;
-                const MAX_ORBITAL: f64 = 271_840.426666667;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Earth-like World";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "ELW(R)";
+                }else {
+                    type_name = "Earth-like World";
+                    description = "CHANGE ME";
+                    type_label = "ELW";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.25040603397;
                 const MAX_ROTATIONAL: f64 = 5_591.70194340926;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("ELW", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::ELW(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::ELW(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "HMC" => { // High Metal Content Planet
-                let ringed: bool = rng().gen_bool(1.0 / 20.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed High Metal Content Planet";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "HMC(R)";
-                }else {
-                    type_name = "High Metal Content Planet";
-                    description = "CHANGE ME";
-                    type_label = "HMC"
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 20.0);
 
                 let rarity: Rarity = Rarity::Common;
 
-                const MIN_DIST: f64 = 0.147454;
-                const MAX_DIST: f64 = 7_488_550.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
-                const MAX_PRESSURE: f64 = 38_894_529_198.7091;
-                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE);
+                const MAX_PRESSURE: f64 = 38_894_529_198.709_1;
+                let rel_percentage_pressure: f64 = random_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rng);
                 let surface_pressure: f64 = val_from_relative_percentage(MIN_PRESSURE, MAX_PRESSURE, rel_percentage_pressure);
 
                 const MIN_TEMP: f64 = 20.0;
@@ -604,968 +857,1031 @@ impl<'a> PlanetType<'a> {
 
                 const MIN_MASSES: f64 = 0.0001;
                 const MAX_MASSES: f64 = 1_397.998047;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 210.242671875;
-                const MAX_RADIUS: f64 = 72_253.984;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
-
-                const MIN_GRAVITY: f64 = 0.028504229273;
-                const MAX_GRAVITY: f64 = 228.220131339448;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let composition: Composition = Composition::random("HMC", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let terraform_state: TerraformState = TerraformState::random("HMC", surface_temp, surface_pressure, gravity, rng);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("HMC", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("HMC", earth_masses, surface_temp, rng);
 
-                const MIN_ORBITAL: f64 = 0.005607748738;
-                const MAX_ORBITAL: f64 = 111_160_422.502844;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ROTATIONAL: f64 = 0.055748183634;
-                const MAX_ROTATIONAL: f64 = 141_426.654814815;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed High Metal Content Planet";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "HMC(R)";
+                }else {
+                    type_name = "High Metal Content Planet";
+                    description = "CHANGE ME";
+                    type_label = "HMC"
+                };
+
+                const MIN_ROTATIONAL: f64 = 0.055748183634;
+                const MAX_ROTATIONAL: f64 = 141_426.654814815;
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("HMC", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::HMC(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::HMC(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: terraform_state.prefix(type_name),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "RIW" => { // Rocky Ice World
-                let ringed: bool = rng().gen_bool(1.0 / 25.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Rocky Ice World (Ringed)";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "RIW(R)";
-                }else {
-                    type_name = "Rocky Ice World";
-                    description = "CHANGE ME";
-                    type_label = "RIW";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 25.0);
 
                 let rarity: Rarity = Rarity::Uncommon;
 
-                const MIN_DIST: f64 = 5.3542;
-                const MAX_DIST: f64 = 5_339_010.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 253_668_685.603375;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 20.0;
                 const MAX_TEMP: f64 = 15_742.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 0.000107;
                 const MAX_MASSES: f64 = 298.62381;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 276.0;
-                const MAX_RADIUS: f64 = 28_515.804;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let composition: Composition = Composition::random("RIW", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("RIW", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("RIW", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.001378452377;
-                const MAX_GRAVITY: f64 = 17.259812728912;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.167619572396;
-                const MAX_ORBITAL: f64 = 58_634_326.6897731;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Rocky Ice World (Ringed)";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "RIW(R)";
+                }else {
+                    type_name = "Rocky Ice World";
+                    description = "CHANGE ME";
+                    type_label = "RIW";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.147149262604;
                 const MAX_ROTATIONAL: f64 = 47_808.7140740741;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("RIW", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::RIW(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::RIW(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "MRB" => { // Metal-Rich Body
-                let ringed: bool = rng().gen_bool(1.0 / 30.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Metal-Rich Body (Ringed)";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "MRB(R)";
-                }else {
-                    type_name = "Metal-Rich Body";
-                    description = "CHANGE ME";
-                    type_label = "MRB";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 30.0);
 
                 let rarity: Rarity = Rarity::Uncommon;
 
-                const MIN_DIST: f64 = 0.087741;
-                const MAX_DIST: f64 = 7_489_630.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
-                const MAX_PRESSURE: f64 = 43_050_307_445.3848;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                const MAX_PRESSURE: f64 = 43_050_307_445.384_8;
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 20.0;
                 const MAX_TEMP: f64 = 47_991.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 0.0001;
                 const MAX_MASSES: f64 = 715.209778;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 137.38325;
-                const MAX_RADIUS: f64 = 20_739.046;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let composition: Composition = Composition::random("MRB", rng);
+                let radius: f64 = solid_radius(earth_masses, composition.radius_factor(), rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let terraform_state: TerraformState = TerraformState::random("MRB", surface_temp, surface_pressure, gravity, rng);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("MRB", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("MRB", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.029231388904;
-                const MAX_GRAVITY: f64 = 199.958389460213;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.005403750475;
-                const MAX_ORBITAL: f64 = 70_018_026.7018299;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Metal-Rich Body (Ringed)";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "MRB(R)";
+                }else {
+                    type_name = "Metal-Rich Body";
+                    description = "CHANGE ME";
+                    type_label = "MRB";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.046768454097;
                 const MAX_ROTATIONAL: f64 = 5_578.24185185;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
+                let materials: Vec<(Material, f64)> = if landable {
+                    random_materials("MRB", &composition, rng)
+                }else {
+                    Vec::new()
+                };
 
-                return Self::MRB(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::MRB(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: terraform_state.prefix(type_name),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: Some(composition),
+                    materials,
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "HGG" => { // Helium Gass Giant
-                let ringed: bool = rng().gen_bool(1.0 / 7.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Helium Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "HGG(R)";
-                }else {
-                    type_name = "Helium Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "HGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 7.0);
 
                 let rarity: Rarity = Rarity::VeryRare;
 
-                const MIN_DIST: f64 = 159.044;
-                const MAX_DIST: f64 = 5_542.96;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 30_887.2179620035;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 53.0;
                 const MAX_TEMP: f64 = 1_701.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 9.003934;
                 const MAX_MASSES: f64 = 5_781.101074;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 16_762.012;
-                const MAX_RADIUS: f64 = 75_900.72;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("HGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("HGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 1.30247301576;
-                const MAX_GRAVITY: f64 = 515.948083392392;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 30.12353209434;
-                const MAX_ORBITAL: f64 = 10_178.4751922996;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Helium Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "HGG(R)";
+                }else {
+                    type_name = "Helium Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "HGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.517331237708;
                 const MAX_ROTATIONAL: f64 = 105.243145496817;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::HGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::HGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "GGGG" => { // Glowing Green Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 5.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Glowing Green Gas Giant (Ringed)";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "GGGG(R)";
-                }else {
-                    type_name = "Glowing Green Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "GGGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 5.0);
 
                 let rarity: Rarity = Rarity::Legendary;
 
-                const MIN_DIST: f64 = 7.77777;
-                const MAX_DIST: f64 = 4_200_069.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 4.20696969;
                 const MAX_PRESSURE: f64 = 420.696969;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 100.0;
                 const MAX_TEMP: f64 = 150.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 1.77777777;
                 const MAX_MASSES: f64 = 910.69696969;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 9_010.666;
-                const MAX_RADIUS: f64 = 77_777.420;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("GGGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("GGGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.2420420420;
-                const MAX_GRAVITY: f64 = 20.4;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.01337;
-                const MAX_ORBITAL: f64 = 3_333_420.696969;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Glowing Green Gas Giant (Ringed)";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "GGGG(R)";
+                }else {
+                    type_name = "Glowing Green Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "GGGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.17777777777777;
                 const MAX_ROTATIONAL: f64 = 317_808.789789789;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::GGGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::GGGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CIGG" => { // Class I Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 3.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Class I Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "CIGG(R)";
-                }else {
-                    type_name = "Class I Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "CIIGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 3.0);
 
                 let rarity: Rarity = Rarity::Common;
 
-                const MIN_DIST: f64 = 3.0;
-                const MAX_DIST: f64 = 4_404_300.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 0.00379244308;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 1.0;
                 const MAX_TEMP: f64 = 150.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 0.734365;
                 const MAX_MASSES: f64 = 911.079224;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 8_079.091;
-                const MAX_RADIUS: f64 = 77_787.584;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("CIGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("CIGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.191422307729;
-                const MAX_GRAVITY: f64 = 19.845980443942;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.001108796332;
-                const MAX_ORBITAL: f64 = 584_242_168.67597;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Class I Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "CIGG(R)";
+                }else {
+                    type_name = "Class I Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "CIGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.001365740741;
                 const MAX_ROTATIONAL: f64 = 7_691_317.0962963;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::CIGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CIGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CIIGG" => { // Class II Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 3.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Class II Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "CIIGG(R)";
-                }else {
-                    type_name = "Class II Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "CIIGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 3.0);
 
                 let rarity: Rarity = Rarity::Rare;
 
-                const MIN_DIST: f64 = 4.41403;
-                const MAX_DIST: f64 = 841_899.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 0.243458896875;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 61.0;
                 const MAX_TEMP: f64 = 250.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 2.641097;
                 const MAX_MASSES: f64 = 1_368.457764;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 10_222.803;
-                const MAX_RADIUS: f64 = 90_000.0;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("CIIGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("CIIGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.252062726485;
-                const MAX_GRAVITY: f64 = 24.584892915592;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.135475762743;
-                const MAX_ORBITAL: f64 = 20_275_545.7549183;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Class II Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "CIIGG(R)";
+                }else {
+                    type_name = "Class II Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "CIIGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.142548313032;
                 const MAX_ROTATIONAL: f64 = 402_652.586939491;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::CIIGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CIIGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CIIIGG" => { // Class III Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 3.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Class III Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "CIIIGG";
-                }else {
-                    type_name = "Class III Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "CIIIGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 3.0);
 
                 let rarity: Rarity = Rarity::Common;
 
-                const MIN_DIST: f64 = 1.61936;
-                const MAX_DIST: f64 = 7_492_280.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 20_305.728515625;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 115.0;
                 const MAX_TEMP: f64 = 800.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 4.296463;
                 const MAX_MASSES: f64 = 3_457.905762;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 12_120.956;
-                const MAX_RADIUS: f64 = 77_849.944;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("CIIIGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("CIIIGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.274524836901;
-                const MAX_GRAVITY: f64 = 199.304829579928;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.018557870653;
-                const MAX_ORBITAL: f64 = 106_016_638.240329;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Class III Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "CIIIGG(R)";
+                }else {
+                    type_name = "Class III Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "CIIIGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.000862268519;
                 const MAX_ROTATIONAL: f64 = 218_760.441309931;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::CIIIGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CIIIGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CIVGG" => { // Class IV Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 3.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Class IV Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "CIVGG(R)";
-                }else {
-                    type_name = "Class IV Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "CIVGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 3.0);
 
                 let rarity: Rarity = Rarity::Uncommon;
 
-                const MIN_DIST: f64 = 0.971942;
-                const MAX_DIST: f64 = 7_492_300.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.420;
                 const MAX_PRESSURE: f64 = 30_707.168942;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 800.0;
                 const MAX_TEMP: f64 = 1450.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 16.754765;
                 const MAX_MASSES: f64 = 5_403.108398;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 17_305.224;
-                const MAX_RADIUS: f64 = 78_291.304;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("CIVGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("CIVGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.441846796047;
-                const MAX_GRAVITY: f64 = 71.175610458804;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.01965354213;
-                const MAX_ORBITAL: f64 = 4_126_230.75555556;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Class IV Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "CIVGG(R)";
+                }else {
+                    type_name = "Class IV Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "CIVGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.057442621296;
                 const MAX_ROTATIONAL: f64 = 10_070_851.1288889;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::CIVGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CIVGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CVGG" => { // Class V Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 3.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Class V Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "CVGG(R)";
-                }else {
-                    type_name = "Class V Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "CVGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 3.0);
 
                 let rarity: Rarity = Rarity::Rare;
 
-                const MIN_DIST: f64 = 0.319073;
-                const MAX_DIST: f64 = 697_615.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.420;
                 const MAX_PRESSURE: f64 = 33_333.333;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 1_400.0;
                 const MAX_TEMP: f64 = 13_712.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 32.504833;
                 const MAX_MASSES: f64 = 13_063.395508;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 20_016.274;
-                const MAX_RADIUS: f64 = 77_806.056;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("CVGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("CVGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.593483029343;
-                const MAX_GRAVITY: f64 = 404.862526550861;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.007973187153;
-                const MAX_ORBITAL: f64 = 4_133_050.70941095;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Class V Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "CVGG(R)";
+                }else {
+                    type_name = "Class V Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "CVGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.062575856586;
                 const MAX_ROTATIONAL: f64 = 2_817.69796296296;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::CVGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CVGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "HRGG" => { // Helium-Rich Gas Giant
-                let ringed: bool = rng().gen_bool(1.0 / 7.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Ringed Helium-Rich Gas Giant";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "HRGG(R)";
-                }else {
-                    type_name = "Helium-Rich Gas Giant";
-                    description = "CHANGE ME";
-                    type_label = "HRGG";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 7.0);
 
                 let rarity: Rarity = Rarity::VeryRare;
 
-                const MIN_DIST: f64 = 2.47592;
-                const MAX_DIST: f64 = 731_999.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 0.0;
                 const MAX_PRESSURE: f64 = 37_777.7;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 1.0;
                 const MAX_TEMP: f64 = 7_787.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 1.028593;
                 const MAX_MASSES: f64 = 4_764.864258;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 9_557.561;
-                const MAX_RADIUS: f64 = 77_743.44;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("HRGG", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("HRGG", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.244444247333;
-                const MAX_GRAVITY: f64 = 81.245461374845;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.16154257787;
-                const MAX_ORBITAL: f64 = 2_572_298.80888889;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Ringed Helium-Rich Gas Giant";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "HRGG(R)";
+                }else {
+                    type_name = "Helium-Rich Gas Giant";
+                    description = "CHANGE ME";
+                    type_label = "HRGG";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.063662365671;
                 const MAX_ROTATIONAL: f64 = 2_029.4611030485;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::HRGG(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::HRGG(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "GGWABL" => { // Gas Giant with Ammonia Based Life
-                let ringed: bool = rng().gen_bool(1.0 / 5.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Gas Giant With Ammonia-Based Life (Ringed)";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "GGWABL(R)";
-                }else {
-                    type_name = "Gas Giant With Ammonia-Based Life";
-                    description = "CHANGE ME";
-                    type_label = "GGWABL";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 5.0);
 
                 let rarity: Rarity = Rarity::VeryRare;
 
-                const MIN_DIST: f64 = 7.19408;
-                const MAX_DIST: f64 = 4_217_110.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 4.20;
                 const MAX_PRESSURE: f64 = 69_696.9;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 100.0;
                 const MAX_TEMP: f64 = 150.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 1.791545;
                 const MAX_MASSES: f64 = 909.972778;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 10_265.455;
-                const MAX_RADIUS: f64 = 77_844.096;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("GGWABL", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("GGWABL", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.245295452982;
-                const MAX_GRAVITY: f64 = 18.147572568807;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.015803241023;
-                const MAX_ORBITAL: f64 = 3_331_479.58196979;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Gas Giant With Ammonia-Based Life (Ringed)";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "GGWABL(R)";
+                }else {
+                    type_name = "Gas Giant With Ammonia-Based Life";
+                    description = "CHANGE ME";
+                    type_label = "GGWABL";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.15729662816;
                 const MAX_ROTATIONAL: f64 = 316_824.865185185;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::GGWABL(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::GGWABL(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "GGWWBL" => { // Gas Giant with Water Baed Life
-                let ringed: bool = rng().gen_bool(1.0 / 5.0);
-
-                let type_name: &str;
-                let description: &str;
-                let type_label: &str;
-                if ringed {
-                    type_name = "Gas Giant With Water-Based Life (Ringed)";
-                    description = "CHANGE ME (WITH RINGS)";
-                    type_label = "GGWWBL(R)";
-                }else {
-                    type_name = "Gas Giant With Water-Based Life";
-                    description = "CHANGE ME";
-                    type_label = "GGWWBL";
-                };
+                let forms_rings: bool = rng.gen_bool(1.0 / 5.0);
 
                 let rarity: Rarity = Rarity::Rare;
 
-                const MIN_DIST: f64 = 5.81925;
-                const MAX_DIST: f64 = 4_214_690.0;
-                let dist_from_arrival: f64 = rng().gen_range(MIN_DIST..=MAX_DIST);
-
                 const MIN_PRESSURE: f64 = 4.20;
                 const MAX_PRESSURE: f64 = 69_420.420691337;
-                let surface_pressure: f64 = rng().gen_range(MIN_PRESSURE..=MAX_PRESSURE);
+                let surface_pressure: f64 = rng.gen_range(MIN_PRESSURE..=MAX_PRESSURE);
 
                 const MIN_TEMP: f64 = 150.0;
                 const MAX_TEMP: f64 = 250.0;
-                let surface_temp: f64 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: f64 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_MASSES: f64 = 2.590262;
                 const MAX_MASSES: f64 = 1_367.778809;
-                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES);
+                let rel_percentage_mass: f64 = random_relative_percentage(MIN_MASSES, MAX_MASSES, rng);
                 let earth_masses: f64 = val_from_relative_percentage(MIN_MASSES, MAX_MASSES, rel_percentage_mass);
 
-                const MIN_RADIUS: f64 = 10_265.455;
-                const MAX_RADIUS: f64 = 77_844.096;
-                let radius: f64 = val_from_relative_percentage(MIN_RADIUS, MAX_RADIUS, rel_percentage_mass);
+                let radius: f64 = gaseous_radius(earth_masses, rng);
+                let gravity: f64 = surface_gravity(earth_masses, radius);
+                let atmosphere: Option<Atmosphere> = Atmosphere::random("GGWWBL", surface_temp, surface_pressure, gravity, rng);
+                let volcanism: Option<Volcanism> = Volcanism::random("GGWWBL", earth_masses, surface_temp, rng);
 
-                const MIN_GRAVITY: f64 = 0.252151647889;
-                const MAX_GRAVITY: f64 = 26.734562844827;
-                let gravity: f64 = val_from_relative_percentage(MIN_GRAVITY, MAX_GRAVITY, rel_percentage_mass);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(radius, bulk_density(earth_masses, radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
-                const MIN_ORBITAL: f64 = 0.002035879559;
-                const MAX_ORBITAL: f64 = 16_447_556.1016134;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
-                let orbital_secs: f64 = orbital_range * 86400.0;
-                let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
+                let type_name: &str;
+                let description: &str;
+                let type_label: &str;
+                if ringed {
+                    type_name = "Gas Giant With Water-Based Life (Ringed)";
+                    description = "CHANGE ME (WITH RINGS)";
+                    type_label = "GGWWBL(R)";
+                }else {
+                    type_name = "Gas Giant With Water-Based Life";
+                    description = "CHANGE ME";
+                    type_label = "GGWWBL";
+                };
 
                 const MIN_ROTATIONAL: f64 = 0.114944627546;
                 const MAX_ROTATIONAL: f64 = 458_802.441481481;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
                 let landable: bool = is_landable(surface_pressure, surface_temp);
                 let explorable: bool = is_explorable(surface_pressure, surface_temp, gravity);
 
-                return Self::GGWWBL(PlanetTypeProperties {
-                    type_label,
-                    type_name,
-                    description,
+                Self::GGWWBL(PlanetTypeProperties {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     landable,
                     explorable,
-                    dist_from_arrival,
+                    terraform_state: TerraformState::NotTerraformable,
+                    dist_from_arrival: 0.0,
                     surface_temp,
                     surface_pressure,
+                    atmosphere,
+                    volcanism,
                     radius,
                     earth_masses,
+                    composition: None,
+                    materials: Vec::new(),
+                    species: Vec::new(),
                     gravity,
-                    orbital_period,
+                    orbital_period: Duration::ZERO,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
-            _ => rand::random(),
+            _ => rng.gen(),
         }
     }
 
-    fn extract_stats(&self) -> Stats {
+    /// Returns a reference to the inner *PlanetTypeProperties* struct, regardless of variant.
+    pub fn info(&self) -> &PlanetTypeProperties {
+        match self {
+            PlanetType::AW(i) | PlanetType::WW(i) | PlanetType::WG(i)
+            | PlanetType::RKB(i) | PlanetType::ICB(i) | PlanetType::ELW(i)
+            | PlanetType::HMC(i) | PlanetType::RIW(i) | PlanetType::MRB(i)
+            | PlanetType::HGG(i) | PlanetType::GGGG(i) | PlanetType::CIGG(i)
+            | PlanetType::CIIGG(i) | PlanetType::CIIIGG(i) | PlanetType::CIVGG(i)
+            | PlanetType::CVGG(i) | PlanetType::HRGG(i) | PlanetType::GGWABL(i)
+            | PlanetType::GGWWBL(i) => i,
+        }
+    }
 
+    /// Returns a mutable reference to the inner *PlanetTypeProperties* struct, regardless of variant.
+    pub fn info_mut(&mut self) -> &mut PlanetTypeProperties {
         match self {
             PlanetType::AW(i) | PlanetType::WW(i) | PlanetType::WG(i)
             | PlanetType::RKB(i) | PlanetType::ICB(i) | PlanetType::ELW(i)
@@ -1573,46 +1889,309 @@ impl<'a> PlanetType<'a> {
             | PlanetType::HGG(i) | PlanetType::GGGG(i) | PlanetType::CIGG(i)
             | PlanetType::CIIGG(i) | PlanetType::CIIIGG(i) | PlanetType::CIVGG(i)
             | PlanetType::CVGG(i) | PlanetType::HRGG(i) | PlanetType::GGWABL(i)
-            | PlanetType::GGWWBL(i) => 
-            {
-                return Stats{
-                    label: i.type_label,
-                    type_name: i.type_name,
-                    description: i.description,
-                    rarity: i.rarity.fetch_rarity(),
-                    ringed: i.ringed,
-                    landable: i.landable,
-                    explorable: i.explorable,
-                    dist_from_arrival: i.dist_from_arrival,
-                    surface_temp: i.surface_temp,
-                    surface_pressure: i.surface_pressure,
-                    radius: i.radius,
-                    earth_masses: i.earth_masses,
-                    gravity: i.gravity,
-                    orbital_period: i.orbital_period,
-                    rotational_period: i.rotational_period,
-                };
-            },
-        };
+            | PlanetType::GGWWBL(i) => i,
+        }
+    }
+
+    /// Returns the type identifier of the *PlanetType*, e.g. "HMC".
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            PlanetType::AW(_) => "AW",
+            PlanetType::WW(_) => "WW",
+            PlanetType::WG(_) => "WG",
+            PlanetType::RKB(_) => "RKB",
+            PlanetType::ICB(_) => "ICB",
+            PlanetType::ELW(_) => "ELW",
+            PlanetType::HMC(_) => "HMC",
+            PlanetType::RIW(_) => "RIW",
+            PlanetType::MRB(_) => "MRB",
+            PlanetType::HGG(_) => "HGG",
+            PlanetType::GGGG(_) => "GGGG",
+            PlanetType::CIGG(_) => "CIGG",
+            PlanetType::CIIGG(_) => "CIIGG",
+            PlanetType::CIIIGG(_) => "CIIIGG",
+            PlanetType::CIVGG(_) => "CIVGG",
+            PlanetType::CVGG(_) => "CVGG",
+            PlanetType::HRGG(_) => "HRGG",
+            PlanetType::GGWABL(_) => "GGWABL",
+            PlanetType::GGWWBL(_) => "GGWWBL",
+        }
+    }
+
+    fn extract_stats(&self) -> Stats {
+        let i: &PlanetTypeProperties = self.info();
+        Stats{
+            label: i.type_label.clone(),
+            type_name: i.type_name.clone(),
+            description: i.description.clone(),
+            rarity: i.rarity.fetch_rarity().into(),
+            rings: i.rings.clone(),
+            landable: i.landable,
+            explorable: i.explorable,
+            terraform_state: i.terraform_state,
+            dist_from_arrival: i.dist_from_arrival,
+            surface_temp: i.surface_temp,
+            surface_pressure: i.surface_pressure,
+            atmosphere: i.atmosphere.clone(),
+            volcanism: i.volcanism,
+            radius: i.radius,
+            earth_masses: i.earth_masses,
+            composition: i.composition,
+            materials: i.materials.clone(),
+            species: i.species.clone(),
+            gravity: i.gravity,
+            orbital_period: i.orbital_period,
+            rotational_period: i.rotational_period,
+            orbit: i.orbit,
+        }
     }
 
 }
 
 
-#[derive(Debug)]
-pub struct Planet<'a> {
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Planet {
     pub name: String,
-    pub ptype: PlanetType<'a>,
+    pub ptype: PlanetType,
+    pub moons: Vec<Planet>, // Ordered by distance from the parent planet
+}
+
+impl FromStr for PlanetType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for PlanetType {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
 }
 
-impl<'a> Planet<'a> {
+impl Planet {
     /// Returns an instance of the *Planet* struct with randomly-initialized properties.
     /// Expects a name and a planet-type identifier, but will provide a random *PlanetType* if an invalid identifier is provided.
+    /// Like every constructor but *Planet::from_star()*, the *Planet* is left unplaced until *Planet::place_around()* is called.
     ///
     /// **Valid Identifiers:**
     /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
-    pub fn new(name: String, ptype: &'a str) -> Self {
-        Self{ name, ptype: PlanetType::new(ptype)}
+    pub fn new(name: String, ptype: &str) -> Self {
+        Self::from_rng(name, ptype, &mut rng())
+    }
+
+    /// Returns an instance of the *Planet* struct, or a *ParseError* if the type identifier is not recognised.
+    ///
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn try_new(name: String, ptype: &str) -> Result<Self, ParseError> {
+        Self::try_from_rng(name, ptype, &mut rng())
+    }
+
+    /// Returns an instance of the *Planet* struct with properties drawn from the provided *Rng*,
+    /// or a *ParseError* if the type identifier is not recognised.
+    ///
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn try_from_rng<R: Rng + ?Sized>(name: String, ptype: &str, rng: &mut R) -> Result<Self, ParseError> {
+        Ok(Self{ name, ptype: PlanetType::try_from_rng(ptype, rng)?, moons: Vec::new()})
+    }
+
+    /// Returns an instance of the *Planet* struct with properties drawn from the provided *Rng*.
+    ///
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn from_rng<R: Rng + ?Sized>(name: String, ptype: &str, rng: &mut R) -> Self {
+        Self{ name, ptype: PlanetType::from_rng(ptype, rng), moons: Vec::new()}
+    }
+
+    /// Returns an instance of the *Planet* struct with properties generated from a *u64* seed.
+    /// The same seed and type identifier will always yield identical *Stats*.
+    ///
+    /// **Valid Identifiers:**
+    /// [AW, WW, WG, RKB, ICB, ELW, HMC, RIW, MRB, HGG, GGGG, CIGG, CIIGG, CIIIGG, CIVGG, CVGG, HRGG, GGWABL, GGWWBL]
+    pub fn from_seed(name: String, ptype: &str, seed: u64) -> Self {
+        Self::from_rng(name, ptype, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Returns an instance of the *Planet* struct with its type picked from the provided *PlanetTypeWeights*.
+    pub fn from_weights<R: Rng + ?Sized>(name: String, weights: &PlanetTypeWeights, rng: &mut R) -> Self {
+        Self{ name, ptype: weights.sample(rng), moons: Vec::new()}
+    }
+
+    /// Returns an instance of the *Planet* struct with a type that is plausible for the given parent *Star*,
+    /// placed at a distance suited to its type with *place_around()*, and with moons from *generate_moons()*.
+    pub fn from_star<R: Rng + ?Sized>(name: String, star: &Star, rng: &mut R) -> Self {
+        let mut planet: Planet = Self::from_weights(name, &PlanetTypeWeights::for_star(star), rng);
+        planet.place_around(star, rng);
+        planet.generate_moons(star, rng);
+        planet.generate_species(star, rng);
+        planet
+    }
+
+    /// Re-draws the calling *Planet*'s distance from arrival using the habitable zone and frost line of the given *Star*.
+    /// Earth-like and water worlds land in the habitable zone, icy bodies and most gas giants beyond the frost line,
+    /// and hot gas giants close in. The surface temperature is then re-derived from the star's light at that distance,
+    /// along with everything that depends on it: atmosphere, volcanism, terraform state, landability and surface materials.
+    pub fn place_around<R: Rng + ?Sized>(&mut self, star: &Star, rng: &mut R) {
+        let (hz_inner, hz_outer): (f64, f64) = star.habitable_zone_ls();
+        let frost_line: f64 = star.frost_line_ls();
+
+        let (min_dist, max_dist): (f64, f64) = match self.ptype {
+            PlanetType::ELW(_) | PlanetType::WW(_) => (hz_inner, hz_outer),
+            PlanetType::GGWWBL(_) | PlanetType::CIIGG(_) => (hz_inner * 0.7, hz_outer * 1.5),
+            PlanetType::AW(_) | PlanetType::GGWABL(_) => (hz_outer, frost_line * 1.5),
+            PlanetType::ICB(_) => (frost_line, frost_line * 30.0),
+            PlanetType::RIW(_) => (frost_line * 0.7, frost_line * 10.0),
+            PlanetType::CIVGG(_) => (hz_inner * 0.03, hz_inner * 0.5),
+            PlanetType::CVGG(_) => (hz_inner * 0.01, hz_inner * 0.3),
+            PlanetType::CIIIGG(_) => (hz_inner * 0.2, frost_line),
+            PlanetType::CIGG(_) | PlanetType::HGG(_) | PlanetType::HRGG(_) | PlanetType::GGGG(_)
+            | PlanetType::WG(_) => (frost_line * 0.8, frost_line * 20.0),
+            PlanetType::MRB(_) => (hz_inner * 0.01, hz_outer),
+            PlanetType::RKB(_) | PlanetType::HMC(_) => (hz_inner * 0.02, frost_line * 10.0),
+        };
+
+        // Keep every body comfortably clear of the star's surface.
+        let surface: f64 = star.class.info().solar_radius * SOLAR_RADIUS_LS * 3.0;
+        let min_dist: f64 = min_dist.max(surface);
+        let max_dist: f64 = max_dist.max(min_dist * 1.01);
+        let dist: f64 = log_uniform(min_dist, max_dist, rng);
+        let system_mass: f64 = star.class.info().solar_masses + self.ptype.info().earth_masses / EARTH_MASSES_PER_SOLAR_MASS;
+        let info: &mut PlanetTypeProperties = self.ptype.info_mut();
+        info.dist_from_arrival = dist;
+        info.surface_temp = surface_temperature(star.luminosity(), dist, info.surface_pressure);
+        rederive_surface(&mut self.ptype, rng);
+        self.set_orbit(OrbitalElements::random(dist, system_mass, rng));
+    }
+
+    /// Sets the orbit of the calling *Planet*, and derives its orbital period from it.
+    pub fn set_orbit(&mut self, orbit: OrbitalElements) {
+        let info: &mut PlanetTypeProperties = self.ptype.info_mut();
+        info.orbital_period = orbit.period();
+        info.orbit = Some(orbit);
+    }
+
+    /// Returns the position of the *Planet* relative to its parent, in light-seconds, at *t* seconds after epoch.
+    /// Returns *None* if the *Planet* has no orbit.
+    pub fn position_at(&self, t: f64) -> Option<Vector3> {
+        self.ptype.info().orbit.map(|orbit| orbit.position_at(t))
+    }
+
+    /// Returns the velocity of the *Planet* relative to its parent, in kilometers per second, at *t* seconds after epoch.
+    /// Returns *None* if the *Planet* has no orbit.
+    pub fn velocity_at(&self, t: f64) -> Option<Vector3> {
+        self.ptype.info().orbit.map(|orbit| orbit.velocity_at(t))
+    }
+
+    /// Returns the mean density of the *Planet* in grams per cubic centimeter.
+    pub fn density(&self) -> f64 {
+        let info: &PlanetTypeProperties = self.ptype.info();
+        bulk_density(info.earth_masses, info.radius)
+    }
+
+    /// Returns the fluid Roche limit of the *Planet* for a satellite of the given density (g/cm³), in light-seconds.
+    /// Any moon orbiting closer than this would be torn apart by tidal forces.
+    pub fn roche_limit_ls(&self, satellite_density: f64) -> f64 {
+        let radius_ls: f64 = self.ptype.info().radius * 1_000.0 / LIGHT_SECOND_M;
+        roche_limit(radius_ls, self.density(), satellite_density)
+    }
+
+    /// Returns the radius of the *Planet*'s Hill sphere at periapsis, in light-seconds.
+    /// Returns *None* if the *Planet* has no orbit.
+    pub fn hill_radius_ls(&self) -> Option<f64> {
+        let info: &PlanetTypeProperties = self.ptype.info();
+        let planet_mass: f64 = info.earth_masses / EARTH_MASSES_PER_SOLAR_MASS;
+        info.orbit.map(|orbit| {
+            let parent_mass: f64 = orbit.system_mass - planet_mass;
+            orbit.periapsis() * (planet_mass / (3.0 * parent_mass)).cbrt()
+        })
+    }
+
+    /// Replaces the calling *Planet*'s moons with a freshly-generated set, ordered by distance and named after the planet.
+    /// Moons are solid bodies orbiting between the Roche limit and *MOON_HILL_FRACTION* of the Hill sphere,
+    /// so a *Planet* without an orbit, or one too close to its star, is left without moons.
+    /// Each moon shares its parent's distance from the given host *Star*, so its surface temperature and everything
+    /// that depends on it are derived from the same starlight.
+    pub fn generate_moons<R: Rng + ?Sized>(&mut self, star: &Star, rng: &mut R) {
+        static MOON_WEIGHTS: OnceLock<PlanetTypeWeights> = OnceLock::new();
+        let weights: &PlanetTypeWeights = MOON_WEIGHTS.get_or_init(PlanetTypeWeights::for_moons);
+
+        self.moons.clear();
+        let Some(hill_radius) = self.hill_radius_ls() else {
+            return;
+        };
+        let outer_limit: f64 = hill_radius * MOON_HILL_FRACTION;
+        let parent_mass: f64 = self.ptype.info().earth_masses;
+        let dist_from_arrival: f64 = self.ptype.info().dist_from_arrival;
+
+        let moon_count: u32 = rng.gen_range(moon_count_range(&self.ptype));
+        let mut moons: Vec<Planet> = Vec::new();
+        for _ in 0..moon_count {
+            let mut moon: Planet = Planet::from_weights(String::new(), weights, rng);
+            let moon_mass: f64 = (parent_mass * log_uniform(1e-6, MAX_MOON_MASS_RATIO, rng))
+                .min(moon.ptype.info().earth_masses);
+            rescale_solid_mass(&mut moon.ptype, moon_mass, rng);
+
+            let inner_limit: f64 = self.roche_limit_ls(moon.density());
+            if inner_limit >= outer_limit {
+                continue;
+            };
+            let dist: f64 = log_uniform(inner_limit, outer_limit, rng);
+            let system_mass: f64 = (parent_mass + moon_mass) / EARTH_MASSES_PER_SOLAR_MASS;
+            let mut orbit: OrbitalElements = OrbitalElements::random(dist, system_mass, rng);
+            // Keep the whole orbit, not just its mean distance, inside the stable region.
+            orbit.eccentricity = orbit.eccentricity
+                .min(1.0 - inner_limit / dist)
+                .min(outer_limit / dist - 1.0);
+            let info: &mut PlanetTypeProperties = moon.ptype.info_mut();
+            info.dist_from_arrival = dist_from_arrival;
+            info.surface_temp = surface_temperature(star.luminosity(), dist_from_arrival, info.surface_pressure);
+            rederive_surface(&mut moon.ptype, rng);
+            moon.set_orbit(orbit);
+            moons.push(moon);
+        };
+
+        moons.sort_by(|a, b| {
+            let a_dist: f64 = a.ptype.info().orbit.map_or(0.0, |o| o.semi_major_axis);
+            let b_dist: f64 = b.ptype.info().orbit.map_or(0.0, |o| o.semi_major_axis);
+            a_dist.total_cmp(&b_dist)
+        });
+        for (i, moon) in moons.iter_mut().enumerate() {
+            moon.name = format!("{} {}", self.name, char::from(b'a' + i as u8));
+        };
+        self.moons = moons;
+    }
+
+    /// Replaces the biological signals of the calling *Planet* and its moons with freshly-generated species
+    /// able to survive on them under the light of the given *Star*.
+    pub fn generate_species<R: Rng + ?Sized>(&mut self, star: &Star, rng: &mut R) {
+        let species: Vec<Species> = random_species(self.ptype.info(), star, rng);
+        self.ptype.info_mut().species = species;
+        for moon in self.moons.iter_mut() {
+            moon.generate_species(star, rng);
+        };
+    }
+
+    /// Returns the credit value of analysing every species on the *Planet*, with the first-logged bonus if *first_logged* is set.
+    /// Moons are not included; sum them separately if needed.
+    pub fn exobiology_value(&self, first_logged: bool) -> u64 {
+        self.ptype.info().species.iter()
+            .map(|species| if first_logged { species.first_logged_value() } else { species.value })
+            .sum()
+    }
+
+    /// Returns the credit value of scanning the *Planet*, scaled by its mass.
+    /// Moons are not included; sum them separately if needed.
+    pub fn scan_value(&self, options: &ScanOptions) -> u64 {
+        let info: &PlanetTypeProperties = self.ptype.info();
+        let (base_value, terraform_bonus): (f64, f64) = base_scan_value(&self.ptype);
+        let terraform_bonus: f64 = if info.terraform_state.is_terraformable() { terraform_bonus } else { 0.0 };
+        planet_scan_value(base_value, terraform_bonus, info.earth_masses, options)
     }
 
     /// Returns the *Stats* struct for the currently-calling *Planet* instance.
@@ -1625,27 +2204,188 @@ impl<'a> Planet<'a> {
 
 /* Planetary Util Functions */
 
+/// Returns the scan value of a planet of the given type before its mass and the scan's multipliers are applied,
+/// along with the bonus added when it can be terraformed.
+fn base_scan_value(ptype: &PlanetType) -> (f64, f64) {
+    match ptype {
+        PlanetType::ELW(_) | PlanetType::WW(_) => (64_831.0, 116_295.0),
+        PlanetType::AW(_) => (96_932.0, 0.0),
+        PlanetType::MRB(_) => (21_790.0, 65_631.0),
+        PlanetType::HMC(_) => (9_654.0, 100_677.0),
+        PlanetType::CIIGG(_) => (9_654.0, 0.0),
+        PlanetType::CIGG(_) => (1_656.0, 0.0),
+        PlanetType::RKB(_) => (300.0, 93_328.0),
+        _ => (300.0, 0.0),
+    }
+}
+
+/// Returns a multiplier for how likely a planet type is to form around a star of the given class.
+fn star_affinity(class: &StarClass, planet_type: &str) -> f64 {
+    match class {
+        // Stellar remnants: violent histories leave few temperate worlds behind.
+        StarClass::NS(_) | StarClass::BH(_) | StarClass::D(_) | StarClass::DA(_) | StarClass::DAB(_)
+        | StarClass::DAV(_) | StarClass::DAZ(_) | StarClass::DB(_) | StarClass::DBV(_) | StarClass::DBZ(_)
+        | StarClass::DC(_) | StarClass::DCV(_) | StarClass::DQ(_) => match planet_type {
+            "ELW" => 0.05,
+            "WW" | "GGWWBL" | "GGWABL" => 0.2,
+            "AW" => 0.3,
+            "ICB" | "MRB" | "HMC" => 1.5,
+            _ => 1.0,
+        },
+        // Cool dwarfs: little light to go around, so cold chemistry dominates.
+        StarClass::M(_) | StarClass::MG(_) | StarClass::L(_) | StarClass::T(_) | StarClass::Y(_) => match planet_type {
+            "AW" | "GGWABL" => 3.0,
+            "ICB" | "RIW" | "CIGG" => 1.5,
+            "WW" => 0.7,
+            "ELW" | "GGWWBL" => 0.3,
+            "CIVGG" | "CVGG" => 0.3,
+            _ => 1.0,
+        },
+        // Sun-like stars: the best odds for liquid water.
+        StarClass::F(_) | StarClass::G(_) | StarClass::K(_) => match planet_type {
+            "ELW" => 2.0,
+            "WW" | "GGWWBL" | "CIIGG" => 1.5,
+            _ => 1.0,
+        },
+        // Hot, massive and short-lived stars.
+        StarClass::O(_) | StarClass::OG(_) | StarClass::B(_) | StarClass::BG(_) | StarClass::A(_)
+        | StarClass::AG(_) | StarClass::W(_) | StarClass::WC(_) | StarClass::WN(_) | StarClass::WNC(_)
+        | StarClass::WO(_) => match planet_type {
+            "CIVGG" | "CVGG" | "MRB" => 2.0,
+            "ELW" => 0.5,
+            "AW" | "GGWABL" => 0.3,
+            _ => 1.0,
+        },
+        // Protostars: too young for life to have taken hold.
+        StarClass::AEBE(_) | StarClass::TTS(_) => match planet_type {
+            "ELW" | "GGWWBL" | "GGWABL" => 0.1,
+            _ => 1.0,
+        },
+        _ => 1.0,
+    }
+}
+
+/// Returns a plausible range for the number of moons orbiting a planet of the given type.
+/// Gas giants gather large moon systems, while solid worlds rarely hold on to more than a couple.
+fn moon_count_range(ptype: &PlanetType) -> RangeInclusive<u32> {
+    match ptype {
+        PlanetType::HGG(_) | PlanetType::GGGG(_) | PlanetType::CIGG(_) | PlanetType::CIIGG(_)
+        | PlanetType::CIIIGG(_) | PlanetType::CIVGG(_) | PlanetType::CVGG(_) | PlanetType::HRGG(_)
+        | PlanetType::GGWABL(_) | PlanetType::GGWWBL(_) => 0..=8,
+        PlanetType::WG(_) => 0..=5,
+        PlanetType::AW(_) | PlanetType::WW(_) | PlanetType::RKB(_) | PlanetType::ICB(_)
+        | PlanetType::ELW(_) | PlanetType::HMC(_) | PlanetType::RIW(_) | PlanetType::MRB(_) => 0..=2,
+    }
+}
+
+/// Shrinks a solid body to the given mass, scaling its radius along the same rocky-planet power law
+/// used by *solid_radius()*, re-deriving its surface gravity and regenerating any rings inside its new Roche limit.
+/// Bodies too light to keep their atmosphere lose it, leaving no surface pressure behind.
+/// Everything that follows from the surface conditions is left to *rederive_surface()*.
+fn rescale_solid_mass<R: Rng + ?Sized>(ptype: &mut PlanetType, earth_masses: f64, rng: &mut R) {
+    let info: &mut PlanetTypeProperties = ptype.info_mut();
+    info.radius *= (earth_masses / info.earth_masses).powf(0.279);
+    info.earth_masses = earth_masses;
+    info.gravity = surface_gravity(earth_masses, info.radius);
+    if info.atmosphere.take().and_then(|atmosphere| atmosphere.held_by(info.gravity)).is_none() {
+        info.surface_pressure = 0.0;
+    };
+    if info.rings.is_some() {
+        info.rings = RingSystem::random(info.radius, bulk_density(earth_masses, info.radius), rng);
+    };
+}
+
+/// Re-derives everything about a body that follows from its surface temperature: its atmosphere, volcanism
+/// and terraform state, whether it can be landed on or explored, and the surface materials exposed if it can be landed on.
+fn rederive_surface<R: Rng + ?Sized>(ptype: &mut PlanetType, rng: &mut R) {
+    let planet_type: &str = ptype.identifier();
+    let info: &mut PlanetTypeProperties = ptype.info_mut();
+    info.atmosphere = Atmosphere::random(planet_type, info.surface_temp, info.surface_pressure, info.gravity, rng);
+    info.volcanism = Volcanism::random(planet_type, info.earth_masses, info.surface_temp, rng);
+    info.landable = is_landable(info.surface_pressure, info.surface_temp);
+    info.explorable = is_explorable(info.surface_pressure, info.surface_temp, info.gravity);
+    info.materials = match (info.landable, info.composition) {
+        (true, Some(composition)) => random_materials(planet_type, &composition, rng),
+        _ => Vec::new(),
+    };
+    let terraform_state: TerraformState = TerraformState::random(planet_type, info.surface_temp, info.surface_pressure, info.gravity, rng);
+    set_terraform_state(info, terraform_state);
+}
+
+/// Replaces a body's *TerraformState*, swapping the state's prefix on its type name for the new one.
+fn set_terraform_state(info: &mut PlanetTypeProperties, terraform_state: TerraformState) {
+    if info.terraform_state.is_terraformable() {
+        let prefix: &str = info.terraform_state.fetch_state();
+        if let Some(type_name) = info.type_name.strip_prefix(prefix) {
+            info.type_name = type_name.trim_start().to_string().into();
+        };
+    };
+    if terraform_state.is_terraformable() {
+        info.type_name = format!("{} {}", terraform_state.fetch_state(), info.type_name).into();
+    };
+    info.terraform_state = terraform_state;
+}
+
+/// Returns the surface temperature in Kelvin of a body with the given surface pressure (factors of Earth's atmosphere),
+/// orbiting the given distance (light-seconds) from a star of the given luminosity (solar luminosities).
+/// Starts from the equilibrium temperature of a body with Earth's albedo, warmed by a greenhouse effect
+/// that grows with the logarithm of the pressure.
+pub fn surface_temperature(luminosity: f64, dist_ls: f64, surface_pressure: f64) -> f64 {
+    let dist_au: f64 = dist_ls / AU_LS;
+    let equilibrium_temp: f64 = EQUILIBRIUM_TEMP_AT_1_AU * luminosity.powf(0.25) / dist_au.sqrt();
+    equilibrium_temp * (1.0 + 0.15 * (1.0 + surface_pressure.max(0.0)).log10())
+}
+
 /// Uses pressure and tempurature to determine whether or not the environment is safe to land a spaceship on.
 fn is_landable(pressure: f64, temp: f64) -> bool {
-    if pressure <= 4.20 && temp <= 666.0 {
-        return true;
-    }else {
-        return false;
-    };
+    pressure <= 4.20 && temp <= 666.0
 }
 
 /// Uses pressure, tempurature, and gravity to determine if the environment is fit for on-foot exploration.
 fn is_explorable(pressure: f64, temp: f64, gravity: f64) -> bool {
-    if pressure <= 2.25 && temp <= 370.0 && gravity <= 4.20 {
-        return true;
+    pressure <= 2.25 && temp <= 370.0 && gravity <= 4.20
+}
+
+/// Uses tempurature and gravity to determine whether the environment could ever be made Earth-like.
+fn is_terraformable(temp: f64, gravity: f64) -> bool {
+    (180.0..=420.0).contains(&temp) && (0.1..=2.5).contains(&gravity)
+}
+
+/// Returns the radius in kilometers of a solid body of the given mass, following the rocky-planet power law
+/// of Chen & Kipping (2017). *radius_factor* scales the radius relative to a body with Earth's bulk composition
+/// (see *Composition::radius_factor()*).
+fn solid_radius<R: Rng + ?Sized>(earth_masses: f64, radius_factor: f64, rng: &mut R) -> f64 {
+    let scatter: f64 = rng.gen_range(0.97..=1.03);
+    EARTH_RADIUS_KM * radius_factor * earth_masses.powf(0.279) * scatter
+}
+
+/// Returns the radius in kilometers of a gaseous body of the given mass, following the piecewise
+/// Neptunian and Jovian power laws of Chen & Kipping (2017).
+fn gaseous_radius<R: Rng + ?Sized>(earth_masses: f64, rng: &mut R) -> f64 {
+    let earth_radii: f64 = if earth_masses < 2.04 {
+        earth_masses.powf(0.279)
+    }else if earth_masses < 132.0 {
+        0.808 * earth_masses.powf(0.589)
     }else {
-        return false;
+        17.74 * earth_masses.powf(-0.044)
     };
+    let scatter: f64 = rng.gen_range(0.95..=1.05);
+    EARTH_RADIUS_KM * earth_radii * scatter
+}
+
+/// Returns the mean density in grams per cubic centimeter of a body of the given mass and radius (km).
+pub fn bulk_density(earth_masses: f64, radius: f64) -> f64 {
+    EARTH_DENSITY * earth_masses / (radius / EARTH_RADIUS_KM).powi(3)
+}
+
+/// Returns the surface gravity in factors of Earth's gravity (g = GM/r²) for a body of the given mass and radius.
+pub fn surface_gravity(earth_masses: f64, radius: f64) -> f64 {
+    earth_masses / (radius / EARTH_RADIUS_KM).powi(2)
 }
 
 /// Returns a random position within a range of numbers (min, max), represented as a relative percentage of that range.
-fn random_relative_percentage(min: f64, max: f64) -> f64 {
-    let n = rng().gen_range(min..=max);
+fn random_relative_percentage<R: Rng + ?Sized>(min: f64, max: f64, rng: &mut R) -> f64 {
+    let n = rng.gen_range(min..=max);
 
     // Formula for relative percentage of the range.
     ((n - min) / (max - min)) * 100.0
@@ -0,0 +1,162 @@
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+use crate::bodies::orbits::log_uniform;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RingClass {
+    Icy,
+    Rocky,
+    MetalRich,
+    Metallic,
+}
+
+/// Every *RingClass*, ordered from least to most dense.
+pub const RING_CLASSES: [RingClass; 4] = [RingClass::Icy, RingClass::Rocky, RingClass::MetalRich, RingClass::Metallic];
+
+impl RingClass {
+    /// Returns the bulk density of the ring material in grams per cubic centimeter.
+    pub fn density(&self) -> f64 {
+        match self {
+            RingClass::Icy => 0.95,
+            RingClass::Rocky => 2.6,
+            RingClass::MetalRich => 4.5,
+            RingClass::Metallic => 7.8,
+        }
+    }
+
+    /// Returns the relative likelihood of a ring forming from this material.
+    pub fn weight(&self) -> f64 {
+        match self {
+            RingClass::Icy => 40.0,
+            RingClass::Rocky => 30.0,
+            RingClass::MetalRich => 20.0,
+            RingClass::Metallic => 10.0,
+        }
+    }
+
+    /// Returns a stringified representation of the calling *RingClass* enum.
+    pub fn fetch_class(&self) -> &'static str {
+        match self {
+            RingClass::Icy => "Icy",
+            RingClass::Rocky => "Rocky",
+            RingClass::MetalRich => "Metal Rich",
+            RingClass::Metallic => "Metallic",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ring {
+    pub class: RingClass,
+    pub inner_radius: f64, // Kilometers, from the parent's centre
+    pub outer_radius: f64, // Kilometers, from the parent's centre
+    pub mass: f64, // Megatonnes
+}
+
+impl Ring {
+    /// Returns the radial width of the *Ring* in kilometers.
+    pub fn width(&self) -> f64 {
+        self.outer_radius - self.inner_radius
+    }
+
+    /// Returns the surface area covered by the *Ring* in square kilometers.
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * (self.outer_radius.powi(2) - self.inner_radius.powi(2))
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RingSystem {
+    pub rings: Vec<Ring>, // Ordered from the innermost ring outwards
+}
+
+impl RingSystem {
+    /// Returns a randomly-generated *RingSystem* for a parent body of the given radius (km) and density (g/cm³).
+    /// Every ring lies between the parent's surface and the Roche limit for its material, so denser rings sit closer in.
+    /// Falls back to a single icy ring if none of the drawn materials fit, and returns *None* only if the parent
+    /// is too diffuse for even icy material to stay bound.
+    pub fn random<R: Rng + ?Sized>(parent_radius: f64, parent_density: f64, rng: &mut R) -> Option<Self> {
+        let index: WeightedIndex<f64> = WeightedIndex::new(RING_CLASSES.iter().map(|c| c.weight()))
+            .expect("RingClass weights are valid");
+        let ring_count: usize = rng.gen_range(1..=3);
+        let mut classes: Vec<RingClass> = (0..ring_count).map(|_| RING_CLASSES[index.sample(rng)]).collect();
+        classes.sort_by(|a, b| b.density().total_cmp(&a.density()));
+
+        let surface: f64 = parent_radius * 1.05;
+        let mut inner_edge: f64 = surface;
+        let mut rings: Vec<Ring> = Vec::new();
+        for class in classes {
+            let limit: f64 = roche_limit(parent_radius, parent_density, class.density());
+            if let Some(ring) = random_ring(class, inner_edge, limit, rng) {
+                inner_edge = ring.outer_radius * 1.02;
+                rings.push(ring);
+            };
+        };
+
+        if rings.is_empty() {
+            let limit: f64 = roche_limit(parent_radius, parent_density, RingClass::Icy.density());
+            rings.extend(random_ring(RingClass::Icy, surface, limit, rng));
+        };
+
+        if rings.is_empty() {
+            None
+        }else {
+            Some(RingSystem { rings })
+        }
+    }
+
+    /// Returns the combined mass of every ring in megatonnes.
+    pub fn total_mass(&self) -> f64 {
+        self.rings.iter().map(|ring| ring.mass).sum()
+    }
+
+    /// Returns the inner radius of the innermost ring in kilometers.
+    pub fn inner_radius(&self) -> f64 {
+        self.rings.first().map_or(0.0, |ring| ring.inner_radius)
+    }
+
+    /// Returns the outer radius of the outermost ring in kilometers.
+    pub fn outer_radius(&self) -> f64 {
+        self.rings.last().map_or(0.0, |ring| ring.outer_radius)
+    }
+
+    /// Returns true if any ring in the system is made of the given material.
+    pub fn contains(&self, class: RingClass) -> bool {
+        self.rings.iter().any(|ring| ring.class == class)
+    }
+}
+
+
+/* Ring Util Functions */
+
+/// Returns a *Ring* of the given material placed somewhere between *inner_edge* and *limit* (km),
+/// or *None* if the gap is too narrow to hold one.
+fn random_ring<R: Rng + ?Sized>(class: RingClass, inner_edge: f64, limit: f64, rng: &mut R) -> Option<Ring> {
+    if limit <= inner_edge * 1.05 {
+        return None;
+    };
+    let midpoint: f64 = (inner_edge * limit).sqrt();
+    let inner_radius: f64 = log_uniform(inner_edge, midpoint, rng);
+    let outer_radius: f64 = log_uniform(midpoint, limit, rng);
+
+    // Surface density of the ring in kg/m², heavier for denser material.
+    let surface_density: f64 = log_uniform(10.0, 1_000.0, rng) * class.density();
+    let area_m2: f64 = std::f64::consts::PI * (outer_radius.powi(2) - inner_radius.powi(2)) * 1e6;
+    let mass: f64 = area_m2 * surface_density / 1e9;
+
+    Some(Ring { class, inner_radius, outer_radius, mass })
+}
+
+/// Returns the fluid Roche limit, in the same unit as *parent_radius*, for a satellite orbiting a parent body.
+/// Any loose material orbiting closer than this is kept from gathering into a moon by tidal forces.
+pub fn roche_limit(parent_radius: f64, parent_density: f64, satellite_density: f64) -> f64 {
+    2.44 * parent_radius * (parent_density / satellite_density).cbrt()
+}
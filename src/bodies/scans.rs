@@ -0,0 +1,89 @@
+/// Value multiplier for being the first commander to scan a body.
+pub const FIRST_DISCOVERY_MULTIPLIER: f64 = 2.6;
+
+/// Value multiplier for mapping a body's surface in detail.
+pub const MAPPING_MULTIPLIER: f64 = 10.0 / 3.0;
+
+/// Value multiplier for mapping a body nobody else has mapped, though somebody else discovered it.
+pub const FIRST_MAPPED_MULTIPLIER: f64 = 8.0956;
+
+/// Value multiplier for both discovering and mapping a body first.
+/// Applied instead of *FIRST_MAPPED_MULTIPLIER*, as the discovery bonus is paid on top.
+pub const FIRST_DISCOVERED_AND_MAPPED_MULTIPLIER: f64 = 3.699_622_554;
+
+/// Value multiplier for mapping a body with no more probes than its target.
+pub const EFFICIENCY_MULTIPLIER: f64 = 1.25;
+
+/// Lowest value any scanned body can be worth, in credits.
+pub const MIN_SCAN_VALUE: f64 = 500.0;
+
+const PLANET_MASS_FACTOR: f64 = 0.565_918_28;
+const STAR_MASS_FACTOR: f64 = 66.25;
+
+
+/// The circumstances of a scan, which decide the multipliers applied to a body's base value.
+/// Mapping options only apply to planets and moons, as stars cannot be surface-mapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScanOptions {
+    pub first_discovery: bool, // Nobody had scanned the body before
+    pub mapped: bool, // The surface was mapped in detail
+    pub first_mapped: bool, // Nobody had mapped the body before
+    pub efficiency_bonus: bool, // Mapped using no more probes than the target
+}
+
+impl ScanOptions {
+    /// Returns the options for a body discovered and mapped first, with the efficiency bonus.
+    pub fn best() -> Self {
+        ScanOptions {
+            first_discovery: true,
+            mapped: true,
+            first_mapped: true,
+            efficiency_bonus: true,
+        }
+    }
+
+    /// Returns the surface-mapping multiplier for these options, or 1 if the body was not mapped.
+    pub fn mapping_multiplier(&self) -> f64 {
+        if !self.mapped {
+            return 1.0;
+        };
+        let multiplier: f64 = match (self.first_discovery, self.first_mapped) {
+            (true, true) => FIRST_DISCOVERED_AND_MAPPED_MULTIPLIER,
+            (false, true) => FIRST_MAPPED_MULTIPLIER,
+            _ => MAPPING_MULTIPLIER,
+        };
+        if self.efficiency_bonus {
+            multiplier * EFFICIENCY_MULTIPLIER
+        }else {
+            multiplier
+        }
+    }
+}
+
+
+/* Scan Value Util Functions */
+
+/// Returns the credit value of scanning a star with the given base value and mass (solar masses).
+pub fn star_scan_value(base_value: f64, solar_masses: f64, options: &ScanOptions) -> u64 {
+    let mut value: f64 = base_value + solar_masses * base_value / STAR_MASS_FACTOR;
+    if options.first_discovery {
+        value *= FIRST_DISCOVERY_MULTIPLIER;
+    };
+    value.max(MIN_SCAN_VALUE).round() as u64
+}
+
+/// Returns the credit value of scanning a planet with the given base value and mass (Earth masses).
+/// *terraform_bonus* is added to the base value, and should be zero for planets that cannot be terraformed.
+pub fn planet_scan_value(base_value: f64, terraform_bonus: f64, earth_masses: f64, options: &ScanOptions) -> u64 {
+    let base_value: f64 = base_value + terraform_bonus;
+    let mut value: f64 = base_value * (1.0 + PLANET_MASS_FACTOR * earth_masses.powf(0.2));
+    value = (value * options.mapping_multiplier()).max(MIN_SCAN_VALUE);
+    if options.mapped {
+        value += (value * 0.3).max(555.0);
+    };
+    if options.first_discovery {
+        value *= FIRST_DISCOVERY_MULTIPLIER;
+    };
+    value.round() as u64
+}
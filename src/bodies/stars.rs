@@ -1,19 +1,29 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::sync::OnceLock;
 use std::time::Duration;
-use crate::Rarity;
+use crate::{ParseError, Rarity};
+use crate::bodies::orbits::{OrbitalElements, Vector3, log_uniform};
+use crate::bodies::rings::RingSystem;
+use crate::bodies::belts::AsteroidBelt;
+use crate::bodies::scans::{ScanOptions, star_scan_value};
 
 use rand::{
-    distributions::{Distribution, Standard},
+    distributions::{Distribution, Standard, WeightedError, WeightedIndex},
+    rngs::StdRng,
     Rng,
+    SeedableRng,
     thread_rng as rng,
 };
 
-#[derive(Debug)]
-pub struct ClassInfo<'a> {
-    pub type_label: &'a str,
-    pub type_name: &'a str,
-    pub description: &'a str,
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassInfo {
+    pub type_label: Cow<'static, str>,
+    pub type_name: Cow<'static, str>,
+    pub description: Cow<'static, str>,
     pub rarity: Rarity,
-    pub ringed: bool,
+    pub rings: Option<RingSystem>, // None for stars without rings
     pub scoopable: bool,
     pub boostable: bool,
     pub age: u64, // Unit of measurement is "millions of years"
@@ -22,123 +32,220 @@ pub struct ClassInfo<'a> {
     pub surface_temp: i32, // Kelvin
     pub orbital_period: Duration,
     pub rotational_period: Duration,
+    pub orbit: Option<OrbitalElements>, // None for bodies that orbit nothing
 }
 
-#[derive(Debug)]
-pub struct Stats<'a> {
-    pub label: &'a str,
-    pub class_name: &'a str,
-    pub description: &'a str,
-    pub rarity: &'a str,
-    pub ringed: bool,
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    pub label: Cow<'static, str>,
+    pub class_name: Cow<'static, str>,
+    pub description: Cow<'static, str>,
+    pub spectral_type: String, // e.g. "G2 V"
+    pub rarity: Cow<'static, str>,
+    pub rings: Option<RingSystem>,
+    pub belts: Vec<AsteroidBelt>,
     pub can_fuel_scoop: bool,
     pub can_fsd_boost: bool,
     pub age: u64,
     pub solar_masses: f64,
     pub solar_radii: f64,
     pub surface_temp: i32,
+    pub luminosity: f64, // Solar luminosities
+    pub absolute_magnitude: f64, // Bolometric
     pub orbital_period: Duration,
     pub rotational_period: Duration,
+    pub orbit: Option<OrbitalElements>,
 }
 
-#[derive(Debug)]
-pub enum StarClass<'a> {
-    O(ClassInfo<'a>),
-    OG(ClassInfo<'a>),
-    B(ClassInfo<'a>),
-    BG(ClassInfo<'a>),
-    A(ClassInfo<'a>),
-    AG(ClassInfo<'a>),
-    F(ClassInfo<'a>),
-    FG(ClassInfo<'a>),
-    G(ClassInfo<'a>),
-    GG(ClassInfo<'a>),
-    K(ClassInfo<'a>),
-    KG(ClassInfo<'a>),
-    M(ClassInfo<'a>),
-    MG(ClassInfo<'a>),
-    L(ClassInfo<'a>),
-    T(ClassInfo<'a>),
-    Y(ClassInfo<'a>),
-    AEBE(ClassInfo<'a>),
-    TTS(ClassInfo<'a>),
-    C(ClassInfo<'a>),
-    CJ(ClassInfo<'a>),
-    CN(ClassInfo<'a>),
-    MS(ClassInfo<'a>),
-    S(ClassInfo<'a>),
-    W(ClassInfo<'a>),
-    WC(ClassInfo<'a>),
-    WN(ClassInfo<'a>),
-    WNC(ClassInfo<'a>),
-    WO(ClassInfo<'a>),
-    NS(ClassInfo<'a>),
-    D(ClassInfo<'a>),
-    DA(ClassInfo<'a>),
-    DAB(ClassInfo<'a>),
-    DAV(ClassInfo<'a>),
-    DAZ(ClassInfo<'a>),
-    DB(ClassInfo<'a>),
-    DBV(ClassInfo<'a>),
-    DBZ(ClassInfo<'a>),
-    DC(ClassInfo<'a>),
-    DCV(ClassInfo<'a>),
-    DQ(ClassInfo<'a>),
-    BH(ClassInfo<'a>),
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StarClass {
+    O(ClassInfo),
+    OG(ClassInfo),
+    B(ClassInfo),
+    BG(ClassInfo),
+    A(ClassInfo),
+    AG(ClassInfo),
+    F(ClassInfo),
+    FG(ClassInfo),
+    G(ClassInfo),
+    GG(ClassInfo),
+    K(ClassInfo),
+    KG(ClassInfo),
+    M(ClassInfo),
+    MG(ClassInfo),
+    L(ClassInfo),
+    T(ClassInfo),
+    Y(ClassInfo),
+    AEBE(ClassInfo),
+    TTS(ClassInfo),
+    C(ClassInfo),
+    CJ(ClassInfo),
+    CN(ClassInfo),
+    MS(ClassInfo),
+    S(ClassInfo),
+    W(ClassInfo),
+    WC(ClassInfo),
+    WN(ClassInfo),
+    WNC(ClassInfo),
+    WO(ClassInfo),
+    NS(ClassInfo),
+    D(ClassInfo),
+    DA(ClassInfo),
+    DAB(ClassInfo),
+    DAV(ClassInfo),
+    DAZ(ClassInfo),
+    DB(ClassInfo),
+    DBV(ClassInfo),
+    DBZ(ClassInfo),
+    DC(ClassInfo),
+    DCV(ClassInfo),
+    DQ(ClassInfo),
+    BH(ClassInfo),
 }
 
-/* Enables Fetching a random variant of the StarClass enum */
-impl<'a> Distribution<StarClass<'a>> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> StarClass<'a> {
-        match rng.gen_range(0..=41) {
-            0 => StarClass::new("O"),
-            1 => StarClass::new("OG"),
-            2 => StarClass::new("B"),
-            3 => StarClass::new("BG"),
-            4 => StarClass::new("A"),
-            5 => StarClass::new("AG"),
-            6 => StarClass::new("F"),
-            7 => StarClass::new("FG"),
-            8 => StarClass::new("G"),
-            9 => StarClass::new("GG"),
-            10 => StarClass::new("K"),
-            11 => StarClass::new("KG"),
-            12 => StarClass::new("M"),
-            13 => StarClass::new("MG"),
-            14 => StarClass::new("L"),
-            15 => StarClass::new("T"),
-            16 => StarClass::new("Y"),
-            17 => StarClass::new("AEBE"),
-            18 => StarClass::new("TTS"),
-            19 => StarClass::new("C"),
-            20 => StarClass::new("CJ"),
-            21 => StarClass::new("CN"),
-            22 => StarClass::new("MS"),
-            23 => StarClass::new("S"),
-            24 => StarClass::new("W"),
-            25 => StarClass::new("WC"),
-            26 => StarClass::new("WN"),
-            27 => StarClass::new("WNC"),
-            28 => StarClass::new("WO"),
-            29 => StarClass::new("NS"),
-            30 => StarClass::new("D"),
-            31 => StarClass::new("DA"),
-            32 => StarClass::new("DAB"),
-            33 => StarClass::new("DAV"),
-            34 => StarClass::new("DAZ"),
-            35 => StarClass::new("DB"),
-            36 => StarClass::new("DBV"),
-            37 => StarClass::new("DBZ"),
-            38 => StarClass::new("DC"),
-            39 => StarClass::new("DCV"),
-            40 => StarClass::new("DQ"),
-            41 => StarClass::new("BH"),
-            _ => panic!("Invalid StarClass!"),
-        }
+/// Effective surface temperature of the Sun in Kelvin.
+pub const SOLAR_TEMP: f64 = 5_772.0;
+
+/// Absolute bolometric magnitude of the Sun.
+pub const SOLAR_BOLOMETRIC_MAGNITUDE: f64 = 4.74;
+
+/// One astronomical unit, in light-seconds.
+pub const AU_LS: f64 = 499.004_784;
+
+/// Radius of the Sun, in light-seconds.
+pub const SOLAR_RADIUS_LS: f64 = 2.320_6;
+
+/// Radius of the Sun, in kilometers.
+pub const SOLAR_RADIUS_KM: f64 = 695_700.0;
+
+/// Mean density of the Sun in grams per cubic centimeter.
+pub const SOLAR_DENSITY: f64 = 1.408;
+
+/// Every valid *StarClass* identifier, as accepted by *StarClass::try_new()* and *str::parse()*.
+pub const STAR_CLASS_IDENTIFIERS: [&str; 42] = [
+    "O", "OG", "B", "BG", "A", "AG", "F", "FG", "G", "GG", "K", "KG", "M", "MG", "L", "T", "Y", "AE/BE", "TTS", "C", "CJ", "CN", "MS", "S", "W", "WC", "WN", "WNC", "WO", "NS", "D", "DA", "DAB", "DAV", "DAZ", "DB", "DBV", "DBZ", "DC", "DCV", "DQ", "BH",
+];
+
+/// Base *Rarity* tier and stellar-population multiplier for every *StarClass* identifier.
+/// Multipliers adjust classes sharing a tier so that M and K dwarfs dominate, as they do in the real galaxy.
+const STAR_CLASS_POPULATION: [(&str, Rarity, f64); 42] = [
+    ("O", Rarity::VeryRare, 0.1),
+    ("OG", Rarity::ExtremelyRare, 0.1),
+    ("B", Rarity::Rare, 1.0),
+    ("BG", Rarity::VeryRare, 0.5),
+    ("A", Rarity::Uncommon, 1.0),
+    ("AG", Rarity::VeryRare, 1.0),
+    ("F", Rarity::Common, 1.2),
+    ("FG", Rarity::VeryRare, 2.0),
+    ("G", Rarity::Common, 3.0),
+    ("GG", Rarity::VeryRare, 3.0),
+    ("K", Rarity::Common, 4.0),
+    ("KG", Rarity::ExtremelyRare, 20.0),
+    ("M", Rarity::VeryCommon, 6.0),
+    ("MG", Rarity::VeryRare, 5.0),
+    ("L", Rarity::VeryCommon, 0.5),
+    ("T", Rarity::Common, 1.5),
+    ("Y", Rarity::Common, 0.5),
+    ("AEBE", Rarity::Rare, 0.3),
+    ("TTS", Rarity::VeryCommon, 0.04),
+    ("C", Rarity::ExtremelyRare, 2.0),
+    ("CJ", Rarity::VeryRare, 0.5),
+    ("CN", Rarity::VeryRare, 0.5),
+    ("MS", Rarity::Rare, 0.1),
+    ("S", Rarity::Rare, 0.1),
+    ("W", Rarity::ExtremelyRare, 0.3),
+    ("WC", Rarity::ExtremelyRare, 0.3),
+    ("WN", Rarity::ExtremelyRare, 0.3),
+    ("WNC", Rarity::ExtremelyRare, 0.3),
+    ("WO", Rarity::VeryRare, 0.1),
+    ("NS", Rarity::Rare, 0.3),
+    ("D", Rarity::VeryRare, 10.0),
+    ("DA", Rarity::VeryRare, 40.0),
+    ("DAB", Rarity::VeryRare, 2.0),
+    ("DAV", Rarity::ExtremelyRare, 3.0),
+    ("DAZ", Rarity::ExtremelyRare, 3.0),
+    ("DB", Rarity::VeryRare, 8.0),
+    ("DBV", Rarity::ExtremelyRare, 3.0),
+    ("DBZ", Rarity::ExtremelyRare, 3.0),
+    ("DC", Rarity::VeryRare, 4.0),
+    ("DCV", Rarity::VeryRare, 0.5),
+    ("DQ", Rarity::ExtremelyRare, 3.0),
+    ("BH", Rarity::VeryRare, 0.5),
+];
+
+/// A weighted sampler over *StarClass* identifiers.
+/// The default weights combine each class's base *Rarity* with its share of the stellar population,
+/// but callers may inspect them or supply their own.
+#[derive(Debug, Clone)]
+pub struct StarClassWeights {
+    weights: Vec<(&'static str, f64)>,
+    index: WeightedIndex<f64>,
+}
+
+impl StarClassWeights {
+    /// Returns a new instance of the *StarClassWeights* struct from a list of (identifier, weight) pairs.
+    /// Fails if the list is empty, any weight is negative or not finite, or every weight is zero.
+    pub fn new(weights: Vec<(&'static str, f64)>) -> Result<Self, WeightedError> {
+        if weights.iter().any(|(_, w)| !w.is_finite()) {
+            return Err(WeightedError::InvalidWeight);
+        };
+        let index: WeightedIndex<f64> = WeightedIndex::new(weights.iter().map(|(_, w)| *w))?;
+        Ok(StarClassWeights { weights, index })
+    }
+
+    /// Returns the (identifier, weight) pairs used by this sampler.
+    pub fn weights(&self) -> &[(&'static str, f64)] {
+        &self.weights
+    }
+
+    /// Returns the weight assigned to a class identifier, or *None* if the identifier is not in the table.
+    pub fn weight_of(&self, class: &str) -> Option<f64> {
+        self.weights.iter().find(|(id, _)| *id == class).map(|(_, w)| *w)
+    }
+
+    /// Returns a copy of these weights with the given class identifier's weight replaced (or added).
+    pub fn with_weight(&self, class: &'static str, weight: f64) -> Result<Self, WeightedError> {
+        let mut weights: Vec<(&'static str, f64)> = self.weights.clone();
+        match weights.iter_mut().find(|(id, _)| *id == class) {
+            Some(entry) => entry.1 = weight,
+            None => weights.push((class, weight)),
+        };
+        Self::new(weights)
+    }
+
+    /// Returns a randomly-picked class identifier, with probability proportional to its weight.
+    pub fn sample_identifier<R: Rng + ?Sized>(&self, rng: &mut R) -> &'static str {
+        self.weights[self.index.sample(rng)].0
     }
 }
 
-impl<'a> StarClass<'a> {
+impl Default for StarClassWeights {
+    fn default() -> Self {
+        let weights: Vec<(&'static str, f64)> = STAR_CLASS_POPULATION
+            .iter()
+            .map(|(id, rarity, multiplier)| (*id, rarity.weight() * multiplier))
+            .collect();
+        Self::new(weights).expect("Default StarClass weights are valid")
+    }
+}
+
+impl Distribution<StarClass> for StarClassWeights {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> StarClass {
+        StarClass::from_rng(self.sample_identifier(rng), rng)
+    }
+}
+
+/* Enables Fetching a random variant of the StarClass enum, weighted by rarity */
+impl Distribution<StarClass> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> StarClass {
+        static DEFAULT_WEIGHTS: OnceLock<StarClassWeights> = OnceLock::new();
+        DEFAULT_WEIGHTS.get_or_init(StarClassWeights::default).sample(rng)
+    }
+}
+
+impl StarClass {
 
     /// Returns a new instance of the *StarClass* enum, with randomly-initialized properties.
     /// Accepts desired class identifier as a &str, but will return a random *StarClass* if an invalid identifier is given.
@@ -146,6 +253,36 @@ impl<'a> StarClass<'a> {
     /// **Valid Identifiers:**
     /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
     pub fn new(class: &str) -> Self {
+        Self::from_rng(class, &mut rng())
+    }
+
+    /// Returns a new instance of the *StarClass* enum, or a *ParseError* if the identifier is not recognised.
+    /// "random" is also accepted, and yields a random *StarClass*.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn try_new(class: &str) -> Result<Self, ParseError> {
+        Self::try_from_rng(class, &mut rng())
+    }
+
+    /// Returns a new instance of the *StarClass* enum with properties drawn from the provided *Rng*,
+    /// or a *ParseError* if the identifier is not recognised.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn try_from_rng<R: Rng + ?Sized>(class: &str, rng: &mut R) -> Result<Self, ParseError> {
+        if class != "random" && class != "AEBE" && !STAR_CLASS_IDENTIFIERS.contains(&class) {
+            return Err(ParseError::new("StarClass", class, &STAR_CLASS_IDENTIFIERS));
+        };
+        Ok(Self::from_rng(class, rng))
+    }
+
+    /// Returns a new instance of the *StarClass* enum, with properties drawn from the provided *Rng*.
+    /// The same class identifier and *Rng* state will always yield identical properties.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn from_rng<R: Rng + ?Sized>(class: &str, rng: &mut R) -> Self {
 
         /* Here is where most property values of different star classes are assigned */
         match class {
@@ -154,39 +291,40 @@ impl<'a> StarClass<'a> {
                 let description: &str;
                 let type_label: &str = "O";
 
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = false;
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 1_020;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 2.960938;
                 const MAX_MASS: f64 = 119.9375;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.044562874458;
                 const MAX_RADIUS: f64 = 223.009942683172;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 11_298;
                 const MAX_TEMP: i32 = 115_905;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.035106634236;
                 const MAX_ORBITAL: f64 = 2_579_866.94298409;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.014184147535;
                 const MAX_ROTATIONAL: f64 = 131.100810185185;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
+                let ringed: bool = rings.is_some();
                 let rarity: Rarity;
                 if ringed {
                     type_name = "Ringed Blue Star";
@@ -198,12 +336,12 @@ impl<'a> StarClass<'a> {
                     rarity = Rarity::new("VR");
                 };
 
-                return Self::O(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::O(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -212,43 +350,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "OG" => { // Class O BLue Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "OG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 2;
                 const MAX_AGE: u64 = 1_420;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 2.960938;
                 const MAX_MASS: f64 = 119.9375;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 200.044562874458;
                 const MAX_RADIUS: f64 = 300.009942683172;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 7_108;
                 const MAX_TEMP: i32 = 105_105;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.035106634236;
                 const MAX_ORBITAL: f64 = 2_579_866.94298409;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 50.014184147535;
                 const MAX_ROTATIONAL: f64 = 151.100810185185;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -265,12 +404,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::OG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::OG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -279,7 +418,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "B" => { // Class B (Blue-White) Stars
                 let type_name: &str;
@@ -288,34 +428,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 20.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 20.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 12_040;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.183594;
                 const MAX_MASS: f64 = 120.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.041514797369;
                 const MAX_RADIUS: f64 = 300.9525046844;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 3_835;
                 const MAX_TEMP: i32 = 113_827;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.112869194884;
                 const MAX_ORBITAL: f64 = 105_308_641.990026;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.00495087537;
                 const MAX_ROTATIONAL: f64 = 235.707662037037;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -332,12 +478,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::B(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::B(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -346,43 +492,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "BG" => { // Class B Blue-White Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "BG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 4_976;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 1.398438;
                 const MAX_MASS: f64 = 106.828125;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 250.067210081282;
                 const MAX_RADIUS: f64 = 499.839161512581;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 6_456;
                 const MAX_TEMP: i32 = 30_699;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.074489089769;
                 const MAX_ORBITAL: f64 = 7_381_527.65013553;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.026924294051;
                 const MAX_ROTATIONAL: f64 = 302.311684412442;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -399,12 +546,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::BG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::BG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -413,7 +560,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "A" => { // Class A (Blue-White) Stars
                 let type_name: &str;
@@ -422,34 +570,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 420_069.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 420_069.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 12_224;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.074219;
                 const MAX_MASS: f64 = 105.1875;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.009967209266;
                 const MAX_RADIUS: f64 = 145.437983194824;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 4_271;
                 const MAX_TEMP: i32 = 34_945;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.093999310498;
                 const MAX_ORBITAL: f64 = 66_930_624.0699909;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.042729657257;
                 const MAX_ROTATIONAL: f64 = 254.093055555556;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -466,12 +620,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::A(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::A(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -480,43 +634,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "AG" => { // Class A Blue-White Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "AG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 1;
                 const MAX_AGE: u64 = 4_944;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 1.398438;
                 const MAX_MASS: f64 = 106.222656;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 145.009967209266;
                 const MAX_RADIUS: f64 = 499.995486780733;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 6_414;
                 const MAX_TEMP: i32 = 30_696;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.537583550347;
                 const MAX_ORBITAL: f64 = 5_502_668.04148148;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.090871525521;
                 const MAX_ROTATIONAL: f64 = 284.561458333333;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -533,12 +688,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::AG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::AG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -547,7 +702,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "F" => { // Class F (White) Stars
                 let type_name: &str;
@@ -556,34 +712,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 52.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 52.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_038;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.492188;
                 const MAX_MASS: f64 = 17.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.499396823391;
                 const MAX_RADIUS: f64 = 40.740608599247;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 3_798;
                 const MAX_TEMP: i32 = 34_130;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.086331758854;
                 const MAX_ORBITAL: f64 = 273_578_917.538678;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.198873008519;
                 const MAX_ROTATIONAL: f64 = 414.054418727419;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -600,12 +762,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::F(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::F(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -614,43 +776,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "FG" => { // Class F White Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "FG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 256;
                 const MAX_AGE: u64 = 11_994;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 4.769531;
                 const MAX_MASS: f64 = 45.8125;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 40.499396823391;
                 const MAX_RADIUS: f64 = 214.740608599247;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 4_493;
                 const MAX_TEMP: i32 = 7_499;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 2.459441008391;
                 const MAX_ORBITAL: f64 = 5_528_437.94962963;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 6.445642589225;
                 const MAX_ROTATIONAL: f64 = 1_128.73787037037;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -667,12 +830,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::FG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::FG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -681,7 +844,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "G" => { // Class G (Yellow-White) Stars
                 let type_name: &str;
@@ -690,34 +854,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 142.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 142.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.421875;
                 const MAX_MASS: f64 = 6.253906;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.509979237958;
                 const MAX_RADIUS: f64 = 42.000128563623;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 3_346;
                 const MAX_TEMP: i32 = 18_407;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.086580284965;
                 const MAX_ORBITAL: f64 = 465_068_317.673824;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.460332842512;
                 const MAX_ROTATIONAL: f64 = 540.618148148148;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -734,12 +904,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::G(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::G(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -748,43 +918,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "GG" => { // Class G White-Yellow Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "GG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 2;
                 const MAX_AGE: u64 = 12_792;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.796875;
                 const MAX_MASS: f64 = 20.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 42.199875279318;
                 const MAX_RADIUS: f64 = 142.490128563623;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 4_428;
                 const MAX_TEMP: i32 = 7_496;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 1.012338152917;
                 const MAX_ORBITAL: f64 = 9_810_303.81037037;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.786495949074;
                 const MAX_ROTATIONAL: f64 = 1_262.85137953275;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -801,12 +972,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::GG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::GG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -815,7 +986,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "K" => { // Class K (Yellow-Orange) Stars
                 let type_name: &str;
@@ -824,34 +996,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 27.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 27.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_062;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.265625;
                 const MAX_MASS: f64 = 13.523438;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.188995347232;
                 const MAX_RADIUS: f64 = 58.710197095615;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 2_494;
                 const MAX_TEMP: i32 = 30_024;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.086368436458;
-                const MAX_ORBITAL: f64 = 393_208_219.77324;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                const MAX_ORBITAL: f64 = 393_208_219.773_24;
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.549157895694;
                 const MAX_ROTATIONAL: f64 = 427.435192176401;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -868,12 +1046,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::K(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::K(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -882,43 +1060,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "KG" => { // Class K Yellow-Orange Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "KG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 24;
                 const MAX_AGE: u64 = 13_062;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.449218988419;
                 const MAX_MASS: f64 = 13.097656;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 55.104853824281;
                 const MAX_RADIUS: f64 = 907.527721837034;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 3_255;
                 const MAX_TEMP: i32 = 8_442;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.291476440428;
-                const MAX_ORBITAL: f64 = 203_291_043.91513;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                const MAX_ORBITAL: f64 = 203_291_043.915_13;
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.36237599088;
                 const MAX_ROTATIONAL: f64 = 4_491.19099445159;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -939,12 +1118,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::KG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::KG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -953,7 +1132,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "M" => { // Class M (Red Dwarf) Stars
                 let type_name: &str;
@@ -962,34 +1142,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = true;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 17.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 17.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.019531;
                 const MAX_MASS: f64 = 8.589844;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.086108154063;
                 const MAX_RADIUS: f64 = 67.189511913731;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 508;
                 const MAX_TEMP: i32 = 21_999;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.104032965764;
-                const MAX_ORBITAL: f64 = 577_522_496.93429;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                const MAX_ORBITAL: f64 = 577_522_496.934_29;
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.312319726782;
                 const MAX_ROTATIONAL: f64 = 1_635.77264233275;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1006,12 +1192,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::M(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::M(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1020,43 +1206,44 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "MG" => { // Class M Red Giants+
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "MG";
 
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let scoopable: bool = true;
                 let boostable: bool = false;
 
 
                 const MIN_AGE: u64 = 24;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.199219;
                 const MAX_MASS: f64 = 25.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 67.104853824281;
                 const MAX_RADIUS: f64 = 1_418.28698100353;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 1_859;
                 const MAX_TEMP: i32 = 5_199;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.192648970995;
                 const MAX_ORBITAL: f64 = 21_461_973.522963;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.801408171296;
                 const MAX_ROTATIONAL: f64 = 8_089.64444444444;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1077,12 +1264,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::MG(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::MG(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1091,7 +1278,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "L" => { // Class L Brown Dwarfs
                 let type_name: &str;
@@ -1100,34 +1288,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 15.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 15.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.039063;
                 const MAX_MASS: f64 = 1.132813;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.088881902519;
                 const MAX_RADIUS: f64 = 1.081970196981;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 1_300;
                 const MAX_TEMP: i32 = 2_478;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.186782516539;
                 const MAX_ORBITAL: f64 = 29_584_303.597037;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.330786200625;
                 const MAX_ROTATIONAL: f64 = 5_774.96615652396;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1144,12 +1338,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::L(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::L(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1158,7 +1352,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "T" => { // Class T Brown Dwarfs
                 let type_name: &str;
@@ -1167,34 +1362,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 3.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 3.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.019531;
                 const MAX_MASS: f64 = 0.117188;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.079890076687;
                 const MAX_RADIUS: f64 = 0.282836911574;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 545;
                 const MAX_TEMP: i32 = 1_299;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.054129729097;
                 const MAX_ORBITAL: f64 = 28_190_283.0357905;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.174115841296;
                 const MAX_ROTATIONAL: f64 = 7_354.71481481481;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1209,12 +1410,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::T(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::T(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1223,7 +1424,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "Y" => { // Class Y Brown Dwarfs
                 let type_name: &str;
@@ -1232,34 +1434,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 2.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 2.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.007799999788;
                 const MAX_MASS: f64 = 6.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.022277830338;
                 const MAX_RADIUS: f64 = 3.895288853146;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 128;
                 const MAX_TEMP: i32 = 699;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.063383751725;
                 const MAX_ORBITAL: f64 = 52_604_529.7306997;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.075849250694;
                 const MAX_ROTATIONAL: f64 = 3_905_486.45233072;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1276,12 +1484,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::Y(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::Y(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1290,43 +1498,50 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
-            "AEBE" => { // Herbig AE/BE Protostars
+            "AEBE" | "AE/BE" => { // Herbig AE/BE Protostars
                 let type_name: &str;
                 let description: &str;
                 let type_label: &str = "AE/BE";
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 1.5);
+                let forms_rings: bool = rng.gen_bool(1.0 / 1.5);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 396;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 3.003906;
                 const MAX_MASS: f64 = 119.996094;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.093811030913;
                 const MAX_RADIUS: f64 = 2.775085273904;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 3_353;
                 const MAX_TEMP: i32 = 6_050;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.017480700463;
                 const MAX_ORBITAL: f64 = 5_081_998.18432326;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.025193639688;
                 const MAX_ROTATIONAL: f64 = 2.903862659294;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1341,12 +1556,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::AEBE(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::AEBE(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1355,7 +1570,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "TTS" => { // T Tauri Stars
                 let type_name: &str;
@@ -1364,34 +1580,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = rng().gen_bool(1.0 / 7.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 7.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 210;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.007812	;
                 const MAX_MASS: f64 = 3.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.050780951834;
                 const MAX_RADIUS: f64 = 2.167449673616;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 202;
                 const MAX_TEMP: i32 = 13_143;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.071313013148;
                 const MAX_ORBITAL: f64 = 27_280_924.4968275;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.087478558576;
                 const MAX_ROTATIONAL: f64 = 26_930.8207407407;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -1408,12 +1630,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::TTS(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::TTS(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1422,7 +1644,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "C" => { // Class C Carbon Stars
                 let type_name: &str = "Carbon Star";
@@ -1431,45 +1654,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("ER");
 
 
                 const MIN_AGE: u64 = 4_010;
                 const MAX_AGE: u64 = 13_062;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.460938;
                 const MAX_MASS: f64 = 3.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 13.972106766355;
                 const MAX_RADIUS: f64 = 214.740608599247;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 1_043;
                 const MAX_TEMP: i32 = 5_524;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 65.094700979965;
                 const MAX_ORBITAL: f64 = 9_320_610.99619777;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 18.929997942026;
                 const MAX_ROTATIONAL: f64 = 499.399662499653;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::C(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::C(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1478,7 +1701,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CJ" => { // Class CJ Carbon Stars
                 let type_name: &str = "Carbon Star";
@@ -1487,45 +1711,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("VR");
 
 
                 const MIN_AGE: u64 = 12_000;
                 const MAX_AGE: u64 = 14_000;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.929687976837;
                 const MAX_MASS: f64 = 0.988281;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 29.055603687854;
                 const MAX_RADIUS: f64 = 29.851347646298;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 2_675;
                 const MAX_TEMP: i32 = 2_865;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 26.331756365741;
                 const MAX_ORBITAL: f64 = 10_310_019.602963;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 55.016202293715;
                 const MAX_ROTATIONAL: f64 = 216.036551810463;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::CJ(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CJ(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1534,7 +1758,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "CN" => { // Class CN Carbon Stars
                 let type_name: &str = "Carbon Star";
@@ -1543,45 +1768,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("VR");
 
 
                 const MIN_AGE: u64 = 12_396;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.929687976837;
                 const MAX_MASS: f64 = 0.988281;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 29.055603027344;
                 const MAX_RADIUS: f64 = 29.851347646298;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 2_630;
                 const MAX_TEMP: i32 = 2_872;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 18.508179734155;
                 const MAX_ORBITAL: f64 = 10_968_790.7690251;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 63.244884673924;
                 const MAX_ROTATIONAL: f64 = 269.269212962963;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::CN(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::CN(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1590,7 +1815,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "MS" => { // Class MS Carbon Stars
                 let type_name: &str = "Carbon Star";
@@ -1599,45 +1825,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("R");
 
 
                 const MIN_AGE: u64 = 3_718;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.929687976837;
                 const MAX_MASS: f64 = 1.589844;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 29.055603687854;
                 const MAX_RADIUS: f64 = 35.790951062545;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32  = 2_633;
                 const MAX_TEMP: i32 = 3_699;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 18.833890335648;
                 const MAX_ORBITAL: f64 = 10_009_841.7175037;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 30.08212962963;
                 const MAX_ROTATIONAL: f64 = 316.624097222222;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::MS(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::MS(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1646,7 +1872,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "S" => { // Class S Carbon Stars
                 let type_name: &str = "Carbon Star";
@@ -1655,45 +1882,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("R");
 
 
                 const MIN_AGE: u64 = 3_702;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.53125;
                 const MAX_MASS: f64 = 2.070313;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 29.055603687854;
                 const MAX_RADIUS: f64 = 172.92613902404;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 1_125;
                 const MAX_TEMP: i32 = 3_699;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 17.567633616701;
                 const MAX_ORBITAL: f64 = 10_401_054.72;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 29.669153136088;
                 const MAX_ROTATIONAL: f64 = 552.459444444444;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::S(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::S(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1702,7 +1929,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "W" => { // Wolf-Rayet Stars
                 let type_name: &str = "Wolf-Rayet Star";
@@ -1711,45 +1939,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("ER");
 
 
                 const MIN_AGE: u64 = 1_268;
                 const MAX_AGE: u64 = 13_042;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.46875;
                 const MAX_MASS: f64 = 2.09375;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 3.040821851394;
                 const MAX_RADIUS: f64 = 9.874822253497;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 31_016;
                 const MAX_TEMP: i32 = 192_822;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 6.49068946088;
                 const MAX_ORBITAL: f64 = 9_023_863.13235318;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 1.099536651794;
                 const MAX_ROTATIONAL: f64 = 43.494833594502;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::W(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::W(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1758,7 +1986,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "WN" => { // Class WN Wolf-Rayet Stars
                 let type_name: &str = "Wolf-Rayet Star";
@@ -1767,45 +1996,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("ER");
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 12_798;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.625;
                 const MAX_MASS: f64 = 119.953125;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 3.00021344069;
                 const MAX_RADIUS: f64 = 9.999816419842;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 7;
                 const MAX_TEMP: i32 = 183_987;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.139477267801;
                 const MAX_ORBITAL: f64 = 2_359_243.04083542;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.843257649745;
                 const MAX_ROTATIONAL: f64 = 33.847250941134;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::WN(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::WN(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1814,7 +2043,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "WC" => { // Class WC Wolf-Rayet Stars
                 let type_name: &str = "Wolf-Rayet Star";
@@ -1823,45 +2053,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("ER");
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 12_320;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.597656;
                 const MAX_MASS: f64 = 60.0;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 3.000732066139;
                 const MAX_RADIUS: f64 = 9.999358527678;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 8;
                 const MAX_TEMP: i32 = 159_971;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.156556012188;
                 const MAX_ORBITAL: f64 = 1_509_960.62814815;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.824660282847;
                 const MAX_ROTATIONAL: f64 = 39.088547751296;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::WC(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::WC(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1870,7 +2100,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "WNC" => { // Class WNC Wolf-Rayet Stars
                 let type_name: &str = "Wolf-Rayet Star";
@@ -1879,45 +2110,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("ER");
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 2;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 40.023438;
                 const MAX_MASS: f64 = 119.984375;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 2.998941436655;
                 const MAX_RADIUS: f64 = 9.999175959741;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 1;
                 const MAX_TEMP: i32 = 65_525;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.140384012859;
                 const MAX_ORBITAL: f64 = 1_330_582.66074074;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.883800100498;
                 const MAX_ROTATIONAL: f64 = 5.181077405301;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::WNC(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::WNC(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1926,7 +2157,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "WO" => { // Class WO Wolf-Rayet Stars
                 let type_name: &str = "Wolf-Rayet Star";
@@ -1935,45 +2167,45 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
                 let rarity: Rarity = Rarity::new("VR");
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 2;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 60.003906;
                 const MAX_MASS: f64 = 119.996094;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 3.001471327225;
                 const MAX_RADIUS: f64 = 9.99969421711;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 2;
                 const MAX_TEMP: i32 = 65_532;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.131019580984;
                 const MAX_ORBITAL: f64 = 1_193_413.30962963;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.875076064028;
                 const MAX_ROTATIONAL: f64 = 6.065704210069;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::WO(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::WO(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -1982,7 +2214,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "NS" => { // Neutron Stars, Pulsars, Magnetars
                 let type_name: &str;
@@ -1991,45 +2224,51 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let mut ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let mut forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.425781;
                 const MAX_MASS: f64 = 15.972656;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 let solar_radius: f64 = 0.0000000000001;
 
                 const MIN_TEMP: i32 = 900_001;
                 const MAX_TEMP: i32 = 985_067_520;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.036306160822;
                 const MAX_ORBITAL: f64 = 15_967_999.4814931;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.000100000995;
                 const MAX_ROTATIONAL: f64 = 222_814_898_821.345;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
-                let mut pulsar: bool = rng().gen_bool(1.0 / 2.0);
-                let magnetar: bool = rng().gen_bool(1.0 / 420_000.0);
+                let mut pulsar: bool = rng.gen_bool(1.0 / 2.0);
+                let magnetar: bool = rng.gen_bool(1.0 / 420_000.0);
                 if pulsar {
-                    ringed = false;
+                    forms_rings = false;
                 };
                 if magnetar {
-                    ringed = true;
+                    forms_rings = true;
                     pulsar = false;
                 };
 
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 let rarity: Rarity;
                 if ringed {
@@ -2051,12 +2290,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::NS(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::NS(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2065,7 +2304,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
             "D" => { // White Dwarfs
                 let type_name: &str;
@@ -2074,34 +2314,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 3_792;
                 const MAX_AGE: u64 = 13_062;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.203125;
                 const MAX_MASS: f64 = 1.34375;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.00307855717;
                 const MAX_RADIUS: f64 = 0.023012974983;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 4_701;
                 const MAX_TEMP: i32 = 25_360;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.21584549515;
                 const MAX_ORBITAL: f64 = 12_688_206.3017951;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006999597196;
                 const MAX_ROTATIONAL: f64 = 1.910879991319;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2118,12 +2364,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::D(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::D(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2132,7 +2378,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DA" => { // Class DA White Dwarfs
                 let type_name: &str;
@@ -2141,34 +2388,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 1_298;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.136719;
                 const MAX_MASS: f64 = 1.398438;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.002255576478;
                 const MAX_RADIUS: f64 = 0.024537013528;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 4_503;
                 const MAX_TEMP: i32 = 27_735;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.08118039066;
                 const MAX_ORBITAL: f64 = 17_641_239.144184;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006946098264;
                 const MAX_ROTATIONAL: f64 = 170.867172241211;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2185,12 +2438,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DA(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DA(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2199,7 +2452,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DAB" => { // Class DAB White Dwarfs
                 let type_name: &str;
@@ -2208,34 +2462,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 4_684;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.273438;
                 const MAX_MASS: f64 = 0.710938;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.008850097052;
                 const MAX_RADIUS: f64 = 0.021911620417;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 8_001;
                 const MAX_TEMP: i32 = 24_015;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.090860495417;
                 const MAX_ORBITAL: f64 = 16_629_121.3274074;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006948378576;
                 const MAX_ROTATIONAL: f64 = 39.187155671296;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2252,12 +2512,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DAB(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DAB(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2266,7 +2526,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DAV" => { // Class DAV White Dwarfs
                 let type_name: &str;
@@ -2275,34 +2536,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 420.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 420.0);
 
 
                 const MIN_AGE: u64 = 4_634;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.273438;
                 const MAX_MASS: f64 = 0.710938;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.009155272466;
                 const MAX_RADIUS: f64 = 0.021789548526;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 12_001;
                 const MAX_TEMP: i32 = 24_003;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.089758178565;
                 const MAX_ORBITAL: f64 = 16_942_580.2650275;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006951642919;
                 const MAX_ROTATIONAL: f64 = 3.297920645255;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2319,12 +2586,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DAV(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DAV(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2333,7 +2600,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DAZ" => { // Class DAZ White Dwarfs
                 let type_name: &str;
@@ -2342,34 +2610,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 3_852;
                 const MAX_AGE: u64 = 13_050;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.304688;
                 const MAX_MASS: f64 = 1.332031;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.003566249246;
                 const MAX_RADIUS: f64 = 0.019561765636;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 6_106;
                 const MAX_TEMP: i32 = 24_638;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.226992706736;
                 const MAX_ORBITAL: f64 = 16_140_341.758728;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006972483877;
                 const MAX_ROTATIONAL: f64 = 11.068577835648;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2386,12 +2660,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DAZ(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DAZ(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2400,7 +2674,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DB" => { // Class DB White Dwarfs
                 let type_name: &str;
@@ -2409,34 +2684,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 4_726;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.273438;
                 const MAX_MASS: f64 = 0.710938;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.008972167505;
                 const MAX_RADIUS: f64 = 0.021331785766;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 12_002;
                 const MAX_TEMP: i32 = 24_045;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.099657015116;
                 const MAX_ORBITAL: f64 = 16_218_796.2311111;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006949402118;
                 const MAX_ROTATIONAL: f64 = 47.034012683137;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2453,12 +2734,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DB(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DB(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2467,7 +2748,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DBV" => { // Class DBV White Dwarfs
                 let type_name: &str;
@@ -2476,34 +2758,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 4_798;
                 const MAX_AGE: u64 = 12_063;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.289063;
                 const MAX_MASS: f64 = 0.707031;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.009063720345;
                 const MAX_RADIUS: f64 = 0.020782468728;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 12_002;
                 const MAX_TEMP: i32 = 24_030;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.142419310637;
                 const MAX_ORBITAL: f64 = 15_581_667.5612222;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006961906574;
                 const MAX_ROTATIONAL: f64 = 56.196827057847;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2520,12 +2808,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DBV(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DBV(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2534,7 +2822,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DBZ" => { // Class DBZ White Dwarfs
                 let type_name: &str;
@@ -2543,34 +2832,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 4_642;
                 const MAX_AGE: u64 = 13_040;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.308594;
                 const MAX_MASS: f64 = 0.710938;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.009338378145;
                 const MAX_RADIUS: f64 = 0.020233153127;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 12_014;
                 const MAX_TEMP: i32 = 23_967;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.207983262801;
                 const MAX_ORBITAL: f64 = 14_565_756.7514317;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.007056985498;
                 const MAX_ROTATIONAL: f64 = 1.977933169178;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2587,12 +2882,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DBZ(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DBZ(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2601,7 +2896,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DC" => { // Class DC White Dwarfs
                 let type_name: &str;
@@ -2610,34 +2906,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 1_368;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.140625;
                 const MAX_MASS: f64 = 1.433594;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.000792499803;
                 const MAX_RADIUS: f64 = 0.024597975127;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 4_197;
                 const MAX_TEMP: i32 = 25_232;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.063943922257;
                 const MAX_ORBITAL: f64 = 35_001_189.2064854;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006945021377;
                 const MAX_ROTATIONAL: f64 = 101.582048611111;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2654,12 +2956,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DC(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DC(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2668,7 +2970,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DCV" => { // Class DCV White Dwarfs
                 let type_name: &str;
@@ -2677,34 +2980,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 7_488;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.273438;
                 const MAX_MASS: f64 = 0.710938;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.009094237239;
                 const MAX_RADIUS: f64 = 0.020782468728;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 8_001;
                 const MAX_TEMP: i32 = 12_000;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.111695002801;
                 const MAX_ORBITAL: f64 = 18_198_257.6571134;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.006946161771;
                 const MAX_ROTATIONAL: f64 = 7.813709204468;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2721,12 +3030,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DCV(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DCV(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2735,7 +3044,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "DQ" => { // Class DQ White Dwarfs
                 let type_name: &str;
@@ -2744,34 +3054,40 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = true;
-                let ringed: bool = rng().gen_bool(1.0 / 42.0);
+                let forms_rings: bool = rng.gen_bool(1.0 / 42.0);
 
 
                 const MIN_AGE: u64 = 2_900;
                 const MAX_AGE: u64 = 13_034;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 0.183594;
                 const MAX_MASS: f64 = 1.433594;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.000701057538;
                 const MAX_RADIUS: f64 = 0.02407980297;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let rings: Option<RingSystem> = if forms_rings {
+                    RingSystem::random(solar_radius * SOLAR_RADIUS_KM, stellar_density(solar_masses, solar_radius), rng)
+                }else {
+                    None
+                };
+                let ringed: bool = rings.is_some();
 
                 const MIN_TEMP: i32 = 4_428;
                 const MAX_TEMP: i32 = 25_132;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 37.316534392257;
                 const MAX_ORBITAL: f64 = 2_754_284.08888889;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.142568166458;
                 const MAX_ROTATIONAL: f64 = 1.567897816088;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
@@ -2788,12 +3104,12 @@ impl<'a> StarClass<'a> {
                 };
 
 
-                return Self::DQ(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::DQ(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2802,7 +3118,8 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });  
+                    orbit: None,
+                })
             },
             "BH" => { // Black Holes
                 let type_name: &str = "Black Hole";
@@ -2812,44 +3129,44 @@ impl<'a> StarClass<'a> {
 
                 let scoopable: bool = false;
                 let boostable: bool = false;
-                let ringed: bool = false;
+                let rings: Option<RingSystem> = None;
 
 
                 const MIN_AGE: u64 = 0;
                 const MAX_AGE: u64 = 13_065;
-                let age: u64 = rng().gen_range(MIN_AGE..=MAX_AGE);
+                let age: u64 = rng.gen_range(MIN_AGE..=MAX_AGE);
 
                 const MIN_MASS: f64 = 2.515625;
                 const MAX_MASS: f64 = 220.097656;
-                let solar_masses: f64 = rng().gen_range(MIN_MASS..=MAX_MASS);
+                let solar_masses: f64 = rng.gen_range(MIN_MASS..=MAX_MASS);
 
                 const MIN_RADIUS: f64 = 0.000100007223;
                 const MAX_RADIUS: f64 = 0.000839237567;
-                let solar_radius: f64 = rng().gen_range(MIN_RADIUS..=MAX_RADIUS);
+                let solar_radius: f64 = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
 
                 const MIN_TEMP: i32 = 3_327;
                 const MAX_TEMP: i32 = 10_849;
-                let surface_temp: i32 = rng().gen_range(MIN_TEMP..=MAX_TEMP);
+                let surface_temp: i32 = rng.gen_range(MIN_TEMP..=MAX_TEMP);
 
                 const MIN_ORBITAL: f64 = 0.017480700463;
                 const MAX_ORBITAL: f64 = 6_576_330.64245741;
-                let orbital_range: f64 = rng().gen_range(MIN_ORBITAL..=MAX_ORBITAL);
+                let orbital_range: f64 = rng.gen_range(MIN_ORBITAL..=MAX_ORBITAL);
                 let orbital_secs: f64 = orbital_range * 86_400.0;
                 let orbital_period: Duration = Duration::from_secs_f64(orbital_secs);
 
                 const MIN_ROTATIONAL: f64 = 0.00000001;
                 const MAX_ROTATIONAL: f64 = 0.11111111;
-                let rotational_range: f64 = rng().gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
+                let rotational_range: f64 = rng.gen_range(MIN_ROTATIONAL..=MAX_ROTATIONAL);
                 let rotational_secs: f64 = rotational_range * 86_400.0;
                 let rotational_period: Duration = Duration::from_secs_f64(rotational_secs);
 
 
-                return Self::BH(ClassInfo {
-                    type_label,
-                    type_name,
-                    description,
+                Self::BH(ClassInfo {
+                    type_label: type_label.into(),
+                    type_name: type_name.into(),
+                    description: description.into(),
                     rarity,
-                    ringed,
+                    rings,
                     scoopable,
                     boostable,
                     age,
@@ -2858,14 +3175,15 @@ impl<'a> StarClass<'a> {
                     surface_temp,
                     orbital_period,
                     rotational_period,
-                });
+                    orbit: None,
+                })
             },
-            _ => rand::random(),
+            _ => rng.gen(),
         }
     }
 
-    /// Takes the inner values from the *ClassInfo* struct and packages them in the higher-level *Stats* struct.
-    fn extract_stats(&self) -> Stats {
+    /// Returns a reference to the inner *ClassInfo* struct, regardless of variant.
+    pub fn info(&self) -> &ClassInfo {
         match self {
             Self::O(i) | Self::OG(i) | Self::B(i) | Self::BG(i) | Self::A(i)
              | Self::AG(i) | Self::F(i) | Self::FG(i) | Self::G(i) | Self::GG(i)
@@ -2875,52 +3193,335 @@ impl<'a> StarClass<'a> {
              | Self::WN(i) | Self::WC(i) | Self::WNC(i) | Self::WO(i) | Self::NS(i) 
              | Self::D(i) | Self::DA(i) | Self::DAB(i) | Self::DAV(i) | Self::DAZ(i) 
              | Self::DB(i) | Self::DBV(i) | Self::DBZ(i) | Self::DC(i) | Self::DCV(i) 
-             | Self::DQ(i) | Self::BH(i) =>
-             {
-                return Stats{
-                    label: i.type_label,
-                    class_name: i.type_name,
-                    description: i.description,
-                    rarity: i.rarity.fetch_rarity(),
-                    ringed: i.ringed,
-                    can_fuel_scoop: i.scoopable,
-                    can_fsd_boost: i.boostable,
-                    age: i.age,
-                    solar_masses: i.solar_masses,
-                    solar_radii: i.solar_radius,
-                    surface_temp: i.surface_temp,
-                    orbital_period: i.orbital_period,
-                    rotational_period: i.rotational_period,
-                };
-             },
-        };
+             | Self::DQ(i) | Self::BH(i) => i,
+        }
+    }
+
+    /// Returns a mutable reference to the inner *ClassInfo* struct, regardless of variant.
+    pub fn info_mut(&mut self) -> &mut ClassInfo {
+        match self {
+            Self::O(i) | Self::OG(i) | Self::B(i) | Self::BG(i) | Self::A(i)
+             | Self::AG(i) | Self::F(i) | Self::FG(i) | Self::G(i) | Self::GG(i)
+             | Self::K(i) | Self::KG(i) | Self::M(i) | Self::MG(i) | Self::L(i)
+             | Self::T(i) | Self::Y(i) | Self::AEBE(i) | Self::TTS(i) | Self::C(i)
+             | Self::CJ(i) | Self::CN(i) | Self::MS(i) | Self::S(i) | Self::W(i) 
+             | Self::WN(i) | Self::WC(i) | Self::WNC(i) | Self::WO(i) | Self::NS(i) 
+             | Self::D(i) | Self::DA(i) | Self::DAB(i) | Self::DAV(i) | Self::DAZ(i) 
+             | Self::DB(i) | Self::DBV(i) | Self::DBZ(i) | Self::DC(i) | Self::DCV(i) 
+             | Self::DQ(i) | Self::BH(i) => i,
+        }
+    }
+
+    /// Returns the bolometric luminosity in solar luminosities, derived from radius and surface temperature
+    /// with the Stefan–Boltzmann law: L = R² · T⁴ (in solar units).
+    pub fn luminosity(&self) -> f64 {
+        let info: &ClassInfo = self.info();
+        info.solar_radius.powi(2) * (f64::from(info.surface_temp) / SOLAR_TEMP).powi(4)
+    }
+
+    /// Returns the absolute bolometric magnitude, derived from luminosity.
+    pub fn absolute_magnitude(&self) -> f64 {
+        SOLAR_BOLOMETRIC_MAGNITUDE - 2.5 * self.luminosity().log10()
+    }
+
+    /// Returns the full spectral designation, e.g. "G2 V", "K5 III" or "DA3".
+    /// The subclass is derived from surface temperature, and the luminosity class from the type of star.
+    pub fn spectral_type(&self) -> String {
+        let info: &ClassInfo = self.info();
+        let temp: f64 = f64::from(info.surface_temp);
+        let giant_class: &str = if info.solar_radius >= 100.0 { "I" } else { "III" };
+
+        match self {
+            Self::O(_) | Self::B(_) | Self::A(_) | Self::F(_) | Self::G(_) | Self::K(_) | Self::M(_)
+            | Self::L(_) | Self::T(_) | Self::Y(_) => {
+                format!("{}{} V", info.type_label, mk_subclass(&info.type_label, temp))
+            },
+            Self::OG(_) | Self::BG(_) | Self::AG(_) | Self::FG(_) | Self::GG(_) | Self::KG(_) | Self::MG(_) => {
+                let letter: &str = &info.type_label[..1];
+                format!("{}{} {}", letter, mk_subclass(letter, temp), giant_class)
+            },
+            Self::AEBE(_) => {
+                let letter: &str = if temp >= 10_000.0 { "B" } else { "A" };
+                format!("{}{} Ve", letter, mk_subclass(letter, temp))
+            },
+            Self::TTS(_) => {
+                let letter: &str = if temp >= 5_200.0 { "G" } else if temp >= 3_700.0 { "K" } else { "M" };
+                format!("{}{} Ve", letter, mk_subclass(letter, temp))
+            },
+            Self::C(_) | Self::CJ(_) | Self::CN(_) | Self::MS(_) | Self::S(_) => {
+                format!("{}{} {}", info.type_label, mk_subclass(&info.type_label[..1], temp), giant_class)
+            },
+            Self::W(_) | Self::WC(_) | Self::WN(_) | Self::WNC(_) | Self::WO(_) => {
+                format!("{}{}", info.type_label, mk_subclass("W", temp))
+            },
+            Self::D(_) | Self::DA(_) | Self::DAB(_) | Self::DAV(_) | Self::DAZ(_) | Self::DB(_)
+            | Self::DBV(_) | Self::DBZ(_) | Self::DC(_) | Self::DCV(_) | Self::DQ(_) => {
+                // White dwarfs use a temperature index of 50,400 K / T instead of a subclass.
+                let index: u32 = (50_400.0 / temp).round().clamp(1.0, 12.0) as u32;
+                format!("{}{}", info.type_label, index)
+            },
+            Self::NS(_) | Self::BH(_) => info.type_label.to_string(),
+        }
+    }
+
+    /// Takes the inner values from the *ClassInfo* struct and packages them in the higher-level *Stats* struct.
+    fn extract_stats(&self) -> Stats {
+        let i: &ClassInfo = self.info();
+        Stats{
+            label: i.type_label.clone(),
+            class_name: i.type_name.clone(),
+            description: i.description.clone(),
+            spectral_type: self.spectral_type(),
+            rarity: i.rarity.fetch_rarity().into(),
+            rings: i.rings.clone(),
+            belts: Vec::new(),
+            can_fuel_scoop: i.scoopable,
+            can_fsd_boost: i.boostable,
+            age: i.age,
+            solar_masses: i.solar_masses,
+            solar_radii: i.solar_radius,
+            surface_temp: i.surface_temp,
+            luminosity: self.luminosity(),
+            absolute_magnitude: self.absolute_magnitude(),
+            orbital_period: i.orbital_period,
+            rotational_period: i.rotational_period,
+            orbit: i.orbit,
+        }
     }
 
 
 }
 
-#[derive(Debug)]
-pub struct Star<'a> {
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Star {
     pub name: String,
-    pub class: StarClass<'a>,
+    pub class: StarClass,
+    pub belts: Vec<AsteroidBelt>, // Ordered by distance from the star
 }
 
-impl<'a> Star<'a> {
+impl FromStr for StarClass {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for StarClass {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl Star {
     /// Returns a new instance of the *Star* struct, with randomly-initialized properties.
     /// Expects a name and class identifier, will return with a random *StarClass* if an invalid identifier string is provided.
     /// 
     /// **Valid Identifiers:**
     /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
-    pub fn new(name: String, class: &'a str) -> Self {
-        let class = StarClass::new(class);
-        Star {
-            name,
-            class,
-        }
+    pub fn new(name: String, class: &str) -> Self {
+        Self::from_rng(name, class, &mut rng())
+    }
+
+    /// Returns a new instance of the *Star* struct, or a *ParseError* if the class identifier is not recognised.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn try_new(name: String, class: &str) -> Result<Self, ParseError> {
+        Self::try_from_rng(name, class, &mut rng())
+    }
+
+    /// Returns a new instance of the *Star* struct with properties drawn from the provided *Rng*,
+    /// or a *ParseError* if the class identifier is not recognised.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn try_from_rng<R: Rng + ?Sized>(name: String, class: &str, rng: &mut R) -> Result<Self, ParseError> {
+        let class = StarClass::try_from_rng(class, rng)?;
+        Ok(Self::from_class(name, class, rng))
+    }
+
+    /// Returns a new instance of the *Star* struct, with properties drawn from the provided *Rng*.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn from_rng<R: Rng + ?Sized>(name: String, class: &str, rng: &mut R) -> Self {
+        let class = StarClass::from_rng(class, rng);
+        Self::from_class(name, class, rng)
+    }
+
+    /// Returns a new instance of the *Star* struct, with properties generated from a *u64* seed.
+    /// The same seed and class identifier will always yield identical *Stats*.
+    /// 
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn from_seed(name: String, class: &str, seed: u64) -> Self {
+        Self::from_rng(name, class, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Returns a new instance of the *Star* struct, with its class picked from the provided *StarClassWeights*.
+    pub fn from_weights<R: Rng + ?Sized>(name: String, weights: &StarClassWeights, rng: &mut R) -> Self {
+        let class: StarClass = weights.sample(rng);
+        Self::from_class(name, class, rng)
+    }
+
+    /// Returns a new instance of the *Star* struct wrapping an already-generated *StarClass*,
+    /// with its asteroid belts drawn from the provided *Rng*.
+    pub fn from_class<R: Rng + ?Sized>(name: String, class: StarClass, rng: &mut R) -> Self {
+        let mut star: Star = Star { name, class, belts: Vec::new() };
+        star.generate_belts(rng);
+        star
+    }
+
+    /// Returns the bolometric luminosity of the calling *Star* in solar luminosities.
+    pub fn luminosity(&self) -> f64 {
+        self.class.luminosity()
+    }
+
+    /// Returns the absolute bolometric magnitude of the calling *Star*.
+    pub fn absolute_magnitude(&self) -> f64 {
+        self.class.absolute_magnitude()
+    }
+
+    /// Returns the full spectral designation of the calling *Star*, e.g. "G2 V".
+    pub fn spectral_type(&self) -> String {
+        self.class.spectral_type()
+    }
+
+    /// Returns the inner and outer edges of the calling *Star*'s habitable zone in AU,
+    /// using the stellar flux limits of a runaway and maximum greenhouse (1.1 and 0.53 times Earth's insolation).
+    pub fn habitable_zone_au(&self) -> (f64, f64) {
+        let luminosity: f64 = self.luminosity();
+        ((luminosity / 1.1).sqrt(), (luminosity / 0.53).sqrt())
+    }
+
+    /// Returns the inner and outer edges of the calling *Star*'s habitable zone in light-seconds.
+    pub fn habitable_zone_ls(&self) -> (f64, f64) {
+        let (inner, outer): (f64, f64) = self.habitable_zone_au();
+        (inner * AU_LS, outer * AU_LS)
+    }
+
+    /// Returns the distance of the calling *Star*'s frost (snow) line in AU,
+    /// beyond which volatile ices condense: 2.7 AU scaled by the square root of luminosity.
+    pub fn frost_line_au(&self) -> f64 {
+        2.7 * self.luminosity().sqrt()
+    }
+
+    /// Returns the distance of the calling *Star*'s frost (snow) line in light-seconds.
+    pub fn frost_line_ls(&self) -> f64 {
+        self.frost_line_au() * AU_LS
+    }
+
+    /// Replaces the calling *Star*'s asteroid belts with a freshly-generated set, named "A Belt", "B Belt" and so on.
+    /// A star may host an inner belt just inside its frost line and an outer belt far beyond it.
+    /// Young stars are far more likely to have belts, as collisions and ejections grind them away over time.
+    pub fn generate_belts<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let info: &ClassInfo = self.class.info();
+        let chance: f64 = belt_chance(&self.class) / (1.0 + info.age as f64 / 5_000.0);
+        let frost_line: f64 = self.frost_line_ls();
+        let min_dist: f64 = info.solar_radius * SOLAR_RADIUS_LS * 3.0;
+
+        let inner_dist: f64 = frost_line * log_uniform(0.4, 1.0, rng);
+        let outer_dist: f64 = frost_line * log_uniform(8.0, 20.0, rng);
+        let mut belts: Vec<AsteroidBelt> = Vec::new();
+        for dist in [inner_dist, outer_dist] {
+            if rng.gen_bool(chance) {
+                let designation: char = char::from(b'A' + belts.len() as u8);
+                let name: String = format!("{} {} Belt", self.name, designation);
+                belts.push(AsteroidBelt::random(name, dist.max(min_dist * 1.5), frost_line, rng));
+            };
+        };
+        self.belts = belts;
+    }
+
+    /// Sets the orbit of the calling *Star*, and derives its orbital period from it.
+    pub fn set_orbit(&mut self, orbit: OrbitalElements) {
+        let info: &mut ClassInfo = self.class.info_mut();
+        info.orbital_period = orbit.period();
+        info.orbit = Some(orbit);
+    }
+
+    /// Returns the position of the *Star* relative to its parent, in light-seconds, at *t* seconds after epoch.
+    /// Returns *None* if the *Star* has no orbit.
+    pub fn position_at(&self, t: f64) -> Option<Vector3> {
+        self.class.info().orbit.map(|orbit| orbit.position_at(t))
+    }
+
+    /// Returns the velocity of the *Star* relative to its parent, in kilometers per second, at *t* seconds after epoch.
+    /// Returns *None* if the *Star* has no orbit.
+    pub fn velocity_at(&self, t: f64) -> Option<Vector3> {
+        self.class.info().orbit.map(|orbit| orbit.velocity_at(t))
+    }
+
+    /// Returns the credit value of scanning the *Star*, scaled by its mass.
+    /// Stellar remnants are worth far more than ordinary stars. Mapping options are ignored.
+    pub fn scan_value(&self, options: &ScanOptions) -> u64 {
+        star_scan_value(base_scan_value(&self.class), self.class.info().solar_masses, options)
     }
 
     /// Returns the *Stats* struct for the currently-calling *Star* instance.
     pub fn stats(&self) -> Stats {
-        self.class.extract_stats()
+        Stats {
+            belts: self.belts.clone(),
+            ..self.class.extract_stats()
+        }
+    }
+}
+
+
+/* Stellar Util Functions */
+
+/// Returns the scan value of a star of the given class before its mass and the scan's multipliers are applied.
+fn base_scan_value(class: &StarClass) -> f64 {
+    match class {
+        StarClass::NS(_) | StarClass::BH(_) => 22_628.0,
+        StarClass::D(_) | StarClass::DA(_) | StarClass::DAB(_) | StarClass::DAV(_) | StarClass::DAZ(_)
+        | StarClass::DB(_) | StarClass::DBV(_) | StarClass::DBZ(_) | StarClass::DC(_) | StarClass::DCV(_)
+        | StarClass::DQ(_) => 14_057.0,
+        _ => 1_200.0,
+    }
+}
+
+/// Returns the base chance of a young star of the given class hosting each of its asteroid belts.
+/// Protostars are still wrapped in debris, while stellar remnants have shed most of theirs.
+fn belt_chance(class: &StarClass) -> f64 {
+    match class {
+        StarClass::AEBE(_) | StarClass::TTS(_) => 0.6,
+        StarClass::O(_) | StarClass::B(_) | StarClass::A(_) | StarClass::F(_) | StarClass::G(_)
+        | StarClass::K(_) | StarClass::M(_) => 0.35,
+        StarClass::OG(_) | StarClass::BG(_) | StarClass::AG(_) | StarClass::FG(_) | StarClass::GG(_)
+        | StarClass::KG(_) | StarClass::MG(_) | StarClass::L(_) | StarClass::T(_) | StarClass::Y(_) => 0.2,
+        StarClass::BH(_) => 0.05,
+        _ => 0.1,
     }
-}
\ No newline at end of file
+}
+
+/// Returns the mean density in grams per cubic centimeter of a star with the given mass and radius (in solar units).
+fn stellar_density(solar_masses: f64, solar_radius: f64) -> f64 {
+    SOLAR_DENSITY * solar_masses / solar_radius.powi(3)
+}
+
+/// Returns the Morgan–Keenan subclass (0 = hottest, 9 = coolest) for a temperature,
+/// using the temperature range of the given spectral letter.
+fn mk_subclass(letter: &str, temp: f64) -> u32 {
+    let (min_temp, max_temp): (f64, f64) = match letter {
+        "W" => (30_000.0, 150_000.0),
+        "O" => (30_000.0, 50_000.0),
+        "B" => (10_000.0, 30_000.0),
+        "A" => (7_500.0, 10_000.0),
+        "F" => (6_000.0, 7_500.0),
+        "G" => (5_200.0, 6_000.0),
+        "K" => (3_700.0, 5_200.0),
+        "M" => (2_400.0, 3_700.0),
+        "C" | "S" => (2_000.0, 5_000.0),
+        "L" => (1_300.0, 2_400.0),
+        "T" => (550.0, 1_300.0),
+        "Y" => (250.0, 550.0),
+        _ => return 0,
+    };
+    let position: f64 = ((temp - min_temp) / (max_temp - min_temp)).clamp(0.0, 1.0);
+    (9.0 - (position * 10.0).floor()).clamp(0.0, 9.0) as u32
+}
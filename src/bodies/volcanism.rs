@@ -0,0 +1,175 @@
+use std::fmt;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+
+/// The material a volcanically-active body erupts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VolcanismType {
+    Water,
+    Silicate,
+    Metallic,
+    Ammonia,
+    Methane,
+    CarbonDioxide,
+}
+
+impl VolcanismType {
+    /// Returns a formatted *&str* of the *VolcanismType*, e.g. "Carbon Dioxide".
+    pub fn fetch_name(&self) -> &'static str {
+        match self {
+            VolcanismType::Water => "Water",
+            VolcanismType::Silicate => "Silicate",
+            VolcanismType::Metallic => "Metallic",
+            VolcanismType::Ammonia => "Ammonia",
+            VolcanismType::Methane => "Methane",
+            VolcanismType::CarbonDioxide => "Carbon Dioxide",
+        }
+    }
+
+    /// Returns true for the volatile ices erupted by cryovolcanoes on cold bodies.
+    pub fn is_cryovolcanic(&self) -> bool {
+        !matches!(self, VolcanismType::Silicate | VolcanismType::Metallic)
+    }
+}
+
+
+/// How the material reaches the surface: as flowing magma or as jets of vapour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Eruption {
+    Magma,
+    Geysers,
+}
+
+/// How active a body's volcanism is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VolcanismIntensity {
+    Minor,
+    Normal,
+    Major,
+}
+
+
+/// The volcanic activity of a planet, e.g. "Major Water Geysers" or "Silicate Magma".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Volcanism {
+    pub volcanism_type: VolcanismType,
+    pub eruption: Eruption,
+    pub intensity: VolcanismIntensity,
+}
+
+impl Volcanism {
+    /// Returns randomly-generated *Volcanism* for a planet of the given type identifier, mass and surface temperature (Kelvin),
+    /// or *None* if the planet is geologically dead. Gas giants never have volcanism.
+    /// Heavier bodies keep more internal heat, so are more often and more violently active.
+    /// Rocky and metallic bodies erupt magma, while icy bodies erupt whichever volatiles are frozen at their surface.
+    pub fn random<R: Rng + ?Sized>(planet_type: &str, earth_masses: f64, surface_temp: f64, rng: &mut R) -> Option<Self> {
+        let base_chance: f64 = match planet_type {
+            "ELW" => 0.5,
+            "MRB" => 0.35,
+            "HMC" | "WW" | "AW" => 0.3,
+            "ICB" | "RIW" => 0.25,
+            "RKB" => 0.2,
+            _ => return None,
+        };
+        let heat: f64 = earth_masses.max(0.0).powf(0.3).clamp(0.2, 2.0);
+        if !rng.gen_bool((base_chance * heat).min(0.9)) {
+            return None;
+        };
+
+        let candidates: Vec<(VolcanismType, Eruption, f64)> = candidates(planet_type, surface_temp);
+        if candidates.is_empty() {
+            return None;
+        };
+        let index: WeightedIndex<f64> = WeightedIndex::new(candidates.iter().map(|(_, _, w)| *w))
+            .expect("Volcanism weights are valid");
+        let (volcanism_type, eruption, _) = candidates[index.sample(rng)];
+
+        let intensities: [VolcanismIntensity; 3] = [VolcanismIntensity::Minor, VolcanismIntensity::Normal, VolcanismIntensity::Major];
+        let index: WeightedIndex<f64> = WeightedIndex::new([5.0 / heat, 3.0, heat])
+            .expect("VolcanismIntensity weights are valid");
+
+        Some(Volcanism {
+            volcanism_type,
+            eruption,
+            intensity: intensities[index.sample(rng)],
+        })
+    }
+}
+
+impl fmt::Display for Volcanism {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.intensity {
+            VolcanismIntensity::Minor => write!(f, "Minor ")?,
+            VolcanismIntensity::Major => write!(f, "Major ")?,
+            VolcanismIntensity::Normal => {},
+        };
+        match (self.volcanism_type, self.eruption) {
+            (VolcanismType::Silicate, Eruption::Geysers) => write!(f, "Silicate Vapour Geysers"),
+            (volcanism_type, Eruption::Geysers) => write!(f, "{} Geysers", volcanism_type.fetch_name()),
+            (volcanism_type, Eruption::Magma) => write!(f, "{} Magma", volcanism_type.fetch_name()),
+        }
+    }
+}
+
+
+/* Volcanism Util Functions */
+
+/// Returns the kinds of volcanism a planet of the given type could have at the given surface temperature (Kelvin),
+/// weighted by how likely each is. Volatiles only erupt where they would otherwise be frozen solid,
+/// and molten rock and metal are favoured on scorching surfaces.
+fn candidates(planet_type: &str, surface_temp: f64) -> Vec<(VolcanismType, Eruption, f64)> {
+    let molten: f64 = if surface_temp > 1_000.0 { 3.0 } else { 1.0 };
+    let frozen = |melting_point: f64, weight: f64| if surface_temp < melting_point { weight } else { 0.0 };
+
+    let candidates: Vec<(VolcanismType, Eruption, f64)> = match planet_type {
+        "MRB" => vec![
+            (VolcanismType::Metallic, Eruption::Magma, 6.0 * molten),
+            (VolcanismType::Silicate, Eruption::Magma, 2.0 * molten),
+            (VolcanismType::Silicate, Eruption::Geysers, 1.0),
+        ],
+        "HMC" => vec![
+            (VolcanismType::Metallic, Eruption::Magma, 3.0 * molten),
+            (VolcanismType::Silicate, Eruption::Magma, 4.0 * molten),
+            (VolcanismType::Silicate, Eruption::Geysers, 2.0),
+            (VolcanismType::Water, Eruption::Geysers, frozen(273.0, 1.0)),
+        ],
+        "RKB" => vec![
+            (VolcanismType::Silicate, Eruption::Magma, 4.0 * molten),
+            (VolcanismType::Silicate, Eruption::Geysers, 3.0),
+            (VolcanismType::Metallic, Eruption::Magma, 1.0 * molten),
+            (VolcanismType::CarbonDioxide, Eruption::Geysers, frozen(195.0, 1.0)),
+        ],
+        "ELW" => vec![
+            (VolcanismType::Silicate, Eruption::Magma, 3.0 * molten),
+            (VolcanismType::Silicate, Eruption::Geysers, 2.0),
+            (VolcanismType::Water, Eruption::Geysers, 2.0),
+        ],
+        "WW" => vec![
+            (VolcanismType::Water, Eruption::Geysers, 4.0),
+            (VolcanismType::Water, Eruption::Magma, frozen(373.0, 2.0)),
+            (VolcanismType::Silicate, Eruption::Magma, 1.0 * molten),
+        ],
+        "AW" => vec![
+            (VolcanismType::Ammonia, Eruption::Magma, frozen(240.0, 3.0)),
+            (VolcanismType::Methane, Eruption::Magma, frozen(112.0, 1.0)),
+            (VolcanismType::Water, Eruption::Geysers, frozen(373.0, 1.0)),
+        ],
+        "ICB" | "RIW" => vec![
+            (VolcanismType::Water, Eruption::Geysers, frozen(373.0, 4.0)),
+            (VolcanismType::Water, Eruption::Magma, frozen(273.0, 2.0)),
+            (VolcanismType::Ammonia, Eruption::Magma, frozen(240.0, 2.0)),
+            (VolcanismType::Methane, Eruption::Magma, frozen(112.0, 2.0)),
+            (VolcanismType::CarbonDioxide, Eruption::Geysers, frozen(195.0, 2.0)),
+            (VolcanismType::Silicate, Eruption::Magma, if planet_type == "RIW" { 1.0 } else { 0.0 }),
+        ],
+        _ => Vec::new(),
+    };
+    candidates.into_iter().filter(|(_, _, weight)| *weight > 0.0).collect()
+}
@@ -0,0 +1,193 @@
+use crate::bodies::stars::StarClass;
+use crate::naming::{Boxel, Coordinates, MassCode, ProcGenName, MASS_CODES, SECTOR_SIZE, GRID_ORIGIN, SECTOR_GRID};
+use crate::systems::star_systems::StarSystem;
+
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+
+/// Position of the galactic core, in light-years from Sol.
+pub const GALACTIC_CORE: Coordinates = Coordinates { x: 0.0, y: 0.0, z: 25_900.0 };
+
+/// Stellar density around Sol, in systems per cubic light-year.
+pub const SOLAR_NEIGHBOURHOOD_DENSITY: f64 = 0.004;
+
+/// Share of all systems whose primary star falls under each *MassCode*, from 'a' to 'h'.
+/// Follows the initial mass function, so small red and brown dwarfs far outnumber everything else.
+pub const MASS_CODE_SHARES: [f64; 8] = [0.25, 0.35, 0.2, 0.12, 0.055, 0.0245, 0.00049, 0.00001];
+
+const DISC_SCALE_LENGTH: f64 = 8_500.0; // Light-Years
+const DISC_SCALE_HEIGHT: f64 = 1_000.0; // Light-Years
+const BULGE_DENSITY: f64 = 0.5; // Systems per cubic light-year, at the core
+const BULGE_RADIUS: f64 = 3_000.0; // Light-Years
+
+
+/// A procedurally-generated galaxy. Nothing is stored: the systems in any region are
+/// regenerated on demand from the galaxy's seed, so the same coordinates always yield the same systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Galaxy {
+    pub seed: u64,
+}
+
+/// A star system's entry in the galactic catalogue: where it is and how to generate it.
+/// Call *GalacticSystem::generate()* to build the full *StarSystem*.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GalacticSystem {
+    pub name: ProcGenName,
+    pub position: Coordinates, // Light-Years from Sol
+    pub seed: u64,
+}
+
+impl Galaxy {
+    /// Returns a new instance of the *Galaxy* struct generated from a *u64* seed.
+    pub fn new(seed: u64) -> Self {
+        Galaxy { seed }
+    }
+
+    /// Returns the expected number of star systems per cubic light-year at the given position.
+    /// Combines an exponential disc, thinning away from the core and the galactic plane, with a dense central bulge.
+    pub fn stellar_density(&self, position: Coordinates) -> f64 {
+        let radius: f64 = (position.x - GALACTIC_CORE.x).hypot(position.z - GALACTIC_CORE.z);
+        let height: f64 = (position.y - GALACTIC_CORE.y).abs();
+        let disc: f64 = SOLAR_NEIGHBOURHOOD_DENSITY
+            * (-(radius - GALACTIC_CORE.z) / DISC_SCALE_LENGTH).exp()
+            * (-height / DISC_SCALE_HEIGHT).exp();
+        let bulge: f64 = BULGE_DENSITY * (-(position.distance(&GALACTIC_CORE) / BULGE_RADIUS).powi(2)).exp();
+        disc + bulge
+    }
+
+    /// Returns every star system catalogued in the given boxel, in index order.
+    /// The same galaxy seed and boxel will always yield the same systems.
+    pub fn systems_in(&self, boxel: &Boxel) -> Vec<GalacticSystem> {
+        let mut rng: StdRng = StdRng::seed_from_u64(boxel_seed(self.seed, boxel));
+        let volume: f64 = boxel.size().powi(3);
+        let expected: f64 = self.stellar_density(boxel.centre()) * volume * MASS_CODE_SHARES[boxel.mass_code as usize];
+        let corner: Coordinates = boxel.corner();
+
+        (0..poisson(expected, &mut rng))
+            .map(|index| GalacticSystem {
+                name: boxel.name(index),
+                position: Coordinates {
+                    x: corner.x + rng.gen_range(0.0..boxel.size()),
+                    y: corner.y + rng.gen_range(0.0..boxel.size()),
+                    z: corner.z + rng.gen_range(0.0..boxel.size()),
+                },
+                seed: rng.gen(),
+            })
+            .collect()
+    }
+
+    /// Returns every star system within *radius* light-years of a position, nearest first.
+    /// Only the boxels overlapping the sphere are generated, so keep the radius modest near the core.
+    pub fn systems_near(&self, position: Coordinates, radius: f64) -> Vec<GalacticSystem> {
+        let mut systems: Vec<GalacticSystem> = Vec::new();
+        for mass_code in MASS_CODES {
+            let size: f64 = mass_code.boxel_size();
+            let steps = |value: f64, origin: f64, count: u32| -> (i64, i64) {
+                let extent: f64 = SECTOR_SIZE * f64::from(count);
+                let low: f64 = (value - radius - origin).clamp(0.0, extent - 1e-6);
+                let high: f64 = (value + radius - origin).clamp(0.0, extent - 1e-6);
+                ((low / size).floor() as i64, (high / size).floor() as i64)
+            };
+            let (x0, x1) = steps(position.x, GRID_ORIGIN.x, SECTOR_GRID.0);
+            let (y0, y1) = steps(position.y, GRID_ORIGIN.y, SECTOR_GRID.1);
+            let (z0, z1) = steps(position.z, GRID_ORIGIN.z, SECTOR_GRID.2);
+
+            for ix in x0..=x1 {
+                for iy in y0..=y1 {
+                    for iz in z0..=z1 {
+                        let centre: Coordinates = Coordinates {
+                            x: GRID_ORIGIN.x + (ix as f64 + 0.5) * size,
+                            y: GRID_ORIGIN.y + (iy as f64 + 0.5) * size,
+                            z: GRID_ORIGIN.z + (iz as f64 + 0.5) * size,
+                        };
+                        let Some(boxel) = Boxel::containing(centre, mass_code) else {
+                            continue;
+                        };
+                        systems.extend(
+                            self.systems_in(&boxel)
+                                .into_iter()
+                                .filter(|system| system.position.distance(&position) <= radius),
+                        );
+                    };
+                };
+            };
+        };
+
+        systems.sort_by(|a, b| a.position.distance(&position).total_cmp(&b.position.distance(&position)));
+        systems
+    }
+
+    /// Returns the catalogue entry of the named system, or *None* if no such system exists in this galaxy.
+    pub fn find(&self, name: &ProcGenName) -> Option<GalacticSystem> {
+        let boxel: Boxel = name.region()?;
+        self.systems_in(&boxel).into_iter().nth(name.index as usize)
+    }
+}
+
+impl GalacticSystem {
+    /// Returns the full *StarSystem*, generated from the entry's seed.
+    /// The primary star is always heavy enough to be catalogued under the system's *MassCode*.
+    pub fn generate(&self) -> StarSystem {
+        let mut rng: StdRng = StdRng::seed_from_u64(self.seed);
+        let class: StarClass = primary_class(self.name.mass_code, &mut rng);
+        StarSystem::around_class(self.name.to_string(), class, &mut rng)
+    }
+}
+
+
+/* Galaxy Util Functions */
+
+/// Returns a random *StarClass* whose mass falls under the given *MassCode*, drawn by rejection
+/// from the default class weights so every class keeps its usual share within the mass range.
+fn primary_class<R: Rng + ?Sized>(mass_code: MassCode, rng: &mut R) -> StarClass {
+    loop {
+        let class: StarClass = rng.gen();
+        if MassCode::for_solar_masses(class.info().solar_masses) == mass_code {
+            return class;
+        };
+    };
+}
+
+/// Returns a seed unique to the given boxel in a galaxy, so each boxel can be generated independently.
+fn boxel_seed(galaxy_seed: u64, boxel: &Boxel) -> u64 {
+    let (sx, sy, sz) = boxel.sector;
+    let sector: u64 = u64::from(sx + sy * SECTOR_GRID.0 + sz * SECTOR_GRID.0 * SECTOR_GRID.1);
+    let id: u64 = (sector << 32) | (u64::from(boxel.mass_code as u8) << 24) | u64::from(boxel.number);
+    split_mix(galaxy_seed ^ split_mix(id))
+}
+
+/// The SplitMix64 finaliser, which scatters nearby inputs across the whole *u64* range.
+fn split_mix(value: u64) -> u64 {
+    let mut z: u64 = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Returns a Poisson-distributed count with the given mean.
+/// Large means use the normal approximation, which is indistinguishable at that scale.
+fn poisson<R: Rng + ?Sized>(mean: f64, rng: &mut R) -> u32 {
+    if mean <= 0.0 {
+        return 0;
+    };
+    if mean > 30.0 {
+        let (u1, u2): (f64, f64) = (1.0 - rng.gen::<f64>(), rng.gen::<f64>());
+        let normal: f64 = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+        return (mean + mean.sqrt() * normal).round().max(0.0) as u32;
+    };
+
+    let limit: f64 = (-mean).exp();
+    let mut count: u32 = 0;
+    let mut product: f64 = rng.gen();
+    while product > limit {
+        count += 1;
+        product *= rng.gen::<f64>();
+    };
+    count
+}
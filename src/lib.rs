@@ -1,16 +1,23 @@
+use std::fmt;
 use std::io;
 use std::io::Write;
-use rand::Rng;
-use clearscreen;
+use std::str::FromStr;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::time::Duration;
 use std::thread::sleep;
-use bodies::stars::Star;
+use bodies::stars::{Star, StarClass};
 use bodies::planets::Planet;
+use systems::star_systems::StarSystem;
+use naming::{MassCode, ProcGenName};
 
 pub mod bodies;
+pub mod systems;
+pub mod naming;
+pub mod galaxy;
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rarity {
     VeryCommon,
     Common,
@@ -21,26 +28,55 @@ pub enum Rarity {
     Legendary,
 }
 
+/// Every valid *Rarity* identifier, as accepted by *Rarity::new()* and *str::parse()*.
+pub const RARITY_IDENTIFIERS: [&str; 7] = ["VC", "C", "UC", "R", "VR", "ER", "L"];
+
 impl Rarity {
     /// Returns a new instance of the *Rarity* enum based on an identifier *&str*.
+    /// Panics if an invalid identifier is given, use *Rarity::try_new()* to handle the error instead.
     /// 
     /// **Valid Identifiers:**
     /// [VC, C, UC, R, VR, ER, L]
     pub fn new(rarity: &str) -> Self {
+        match Self::try_new(rarity) {
+            Ok(rarity) => rarity,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a new instance of the *Rarity* enum, or a *ParseError* if the identifier is not recognised.
+    /// 
+    /// **Valid Identifiers:**
+    /// [VC, C, UC, R, VR, ER, L]
+    pub fn try_new(rarity: &str) -> Result<Self, ParseError> {
         match rarity {
-            "VC" => Self::VeryCommon,
-            "C" => Self::Common,
-            "UC" => Self::Uncommon,
-            "R" => Self::Rare,
-            "VR" => Self::VeryRare,
-            "ER" => Self::ExtremelyRare,
-            "L" => Self::Legendary,
-            _ => panic!("Invalid Rarity!"),
+            "VC" => Ok(Self::VeryCommon),
+            "C" => Ok(Self::Common),
+            "UC" => Ok(Self::Uncommon),
+            "R" => Ok(Self::Rare),
+            "VR" => Ok(Self::VeryRare),
+            "ER" => Ok(Self::ExtremelyRare),
+            "L" => Ok(Self::Legendary),
+            _ => Err(ParseError::new("Rarity", rarity, &RARITY_IDENTIFIERS)),
+        }
+    }
+
+    /// Returns the relative sampling weight of the calling *Rarity* tier.
+    /// Each tier is roughly four times less likely than the one before it.
+    pub fn weight(&self) -> f64 {
+        match self {
+            Rarity::VeryCommon => 1_000.0,
+            Rarity::Common => 250.0,
+            Rarity::Uncommon => 60.0,
+            Rarity::Rare => 15.0,
+            Rarity::VeryRare => 4.0,
+            Rarity::ExtremelyRare => 1.0,
+            Rarity::Legendary => 0.25,
         }
     }
 
     /// Returns a stringified representation of the calling *Rarity* enum.
-    pub fn fetch_rarity(&self) -> &str {
+    pub fn fetch_rarity(&self) -> &'static str {
         match self {
             Rarity::VeryCommon => "Very Common",
             Rarity::Common => "Common",
@@ -53,28 +89,122 @@ impl Rarity {
     }
 }
 
+impl FromStr for Rarity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for Rarity {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+
+/// The error returned when an identifier does not name a valid *StarClass*, *PlanetType* or *Rarity*.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: &'static str,
+    input: String,
+    valid: &'static [&'static str],
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: &'static str, input: &str, valid: &'static [&'static str]) -> Self {
+        ParseError {
+            kind,
+            input: input.to_string(),
+            valid,
+        }
+    }
+
+    /// Returns the name of the type that failed to parse, e.g. "StarClass".
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Returns the identifier that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns every identifier that would have been accepted.
+    pub fn valid_identifiers(&self) -> &'static [&'static str] {
+        self.valid
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid {} identifier '{}', expected one of: [{}]", self.kind, self.input, self.valid.join(", "))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 
 /// Returns a randomly-initialized instance of the *Star* struct.
-pub fn create_random_star<'a>() -> Star<'a> {
-    let name: String = format!("star_{:#02x}", rand::thread_rng().gen_range(0x00..=0xffffff));
-    Star::new(name, "random")
+pub fn create_random_star() -> Star {
+    create_random_star_from_rng(&mut rand::thread_rng())
 }
 
 /// Returns a randomly-initialized instance of the *Planet* struct.
-pub fn create_random_planet<'a>() -> Planet<'a> {
-    let name: String = format!("planet_{:#02x}", rand::thread_rng().gen_range(0x00..=0xffffff));
-    Planet::new(name, "random")
+pub fn create_random_planet() -> Planet {
+    create_random_planet_from_rng(&mut rand::thread_rng())
+}
+
+/// Returns an instance of the *Star* struct with its name and properties drawn from the provided *Rng*.
+/// The star is given a procedurally-generated name (see *ProcGenName*) catalogued under its mass.
+pub fn create_random_star_from_rng<R: Rng + ?Sized>(rng: &mut R) -> Star {
+    let class: StarClass = StarClass::from_rng("random", rng);
+    let mass_code: MassCode = MassCode::for_solar_masses(class.info().solar_masses);
+    let name: String = ProcGenName::random(mass_code, rng).to_string();
+    Star::from_class(name, class, rng)
+}
+
+/// Returns an instance of the *Planet* struct with its name and properties drawn from the provided *Rng*.
+pub fn create_random_planet_from_rng<R: Rng + ?Sized>(rng: &mut R) -> Planet {
+    let name: String = format!("planet_{:#02x}", rng.gen_range(0x00..=0xffffff));
+    Planet::from_rng(name, "random", rng)
+}
+
+/// Returns an instance of the *Star* struct generated from a *u64* seed.
+/// The same seed will always yield the same name and *Stats*.
+pub fn create_seeded_star(seed: u64) -> Star {
+    create_random_star_from_rng(&mut StdRng::seed_from_u64(seed))
+}
+
+/// Returns an instance of the *Planet* struct generated from a *u64* seed.
+/// The same seed will always yield the same name and *Stats*.
+pub fn create_seeded_planet(seed: u64) -> Planet {
+    create_random_planet_from_rng(&mut StdRng::seed_from_u64(seed))
+}
+
+/// Returns a randomly-initialized instance of the *StarSystem* struct.
+pub fn create_random_system() -> StarSystem {
+    StarSystem::generate(rand::thread_rng().gen())
+}
+
+/// Returns an instance of the *StarSystem* struct generated from a *u64* seed.
+/// The same seed will always yield the same system.
+pub fn create_seeded_system(seed: u64) -> StarSystem {
+    StarSystem::generate(seed)
 }
 
 /// Returns a randomly-initialized instance of the *Star* struct with the given name and class.
 /// Accepts *&str* StarClass identifiers or "random" for a random star class.
-pub fn create_named_star<'a>(name: String, star_class: &'a str) -> Star<'a> {
+pub fn create_named_star(name: String, star_class: &str) -> Star {
     Star::new(name, star_class)
 }
 
 /// Returns a randomly-initialized instance of the *Planet* struct with the given name and type.
 /// Accepts *&str* Planet type identifiers or "random" for a random planet type.
-pub fn create_named_planet<'a>(name: String, ptype: &'a str) -> Planet<'a> {
+pub fn create_named_planet(name: String, ptype: &str) -> Planet {
     Planet::new(name, ptype)
 }
 
@@ -114,6 +244,20 @@ pub fn input() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bodies::stars::{StarClass, StarClassWeights, AU_LS, SOLAR_RADIUS_LS};
+    use crate::bodies::orbits::{OrbitalElements, Vector3, EARTH_MASSES_PER_SOLAR_MASS, LIGHT_SECOND_M};
+    use crate::bodies::planets::{PlanetType, PlanetTypeWeights, TerraformState, EARTH_RADIUS_KM, MAX_MOON_MASS_RATIO, MOON_HILL_FRACTION, bulk_density, surface_gravity, surface_temperature};
+    use crate::bodies::rings::roche_limit;
+    use crate::bodies::atmospheres::{Atmosphere, AtmosphereDensity, AtmosphereType, Gas, MIN_LIGHT_GAS_GRAVITY};
+    use crate::bodies::volcanism::{Eruption, Volcanism, VolcanismIntensity, VolcanismType};
+    use crate::bodies::composition::{Composition, EARTH_COMPOSITION};
+    use crate::bodies::materials::{Material, MaterialHost, random_materials};
+    use crate::bodies::exobiology::{Species, MAX_GENERA, SPECIES, random_species};
+    use crate::bodies::scans::{ScanOptions, FIRST_DISCOVERY_MULTIPLIER, MIN_SCAN_VALUE};
+    use crate::systems::hierarchy::OrbitalNode;
+    use crate::systems::star_systems::PLANET_STABILITY_FRACTION;
+    use crate::naming::{Boxel, Coordinates, MASS_CODES, sector_coordinates, sector_name};
+    use crate::galaxy::{Galaxy, GalacticSystem, GALACTIC_CORE};
 
     #[test]
     fn test_wait() {
@@ -145,6 +289,787 @@ mod tests {
         println!("{:#?}", earth);
     }
 
+    #[test]
+    fn test_seeded_star() {
+        let a: Star = Star::from_seed(String::from("Sol"), "G", 0x5eed);
+        let b: Star = Star::from_seed(String::from("Sol"), "G", 0x5eed);
+        assert_eq!(a.stats(), b.stats());
+
+        let a: Star = create_seeded_star(420);
+        let b: Star = create_seeded_star(420);
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.stats(), b.stats());
+    }
+
+    #[test]
+    fn test_seeded_planet() {
+        let a: Planet = Planet::from_seed(String::from("Earth"), "ELW", 0x5eed);
+        let b: Planet = Planet::from_seed(String::from("Earth"), "ELW", 0x5eed);
+        assert_eq!(a.stats(), b.stats());
+
+        let a: Planet = create_seeded_planet(69);
+        let b: Planet = create_seeded_planet(69);
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.stats(), b.stats());
+    }
+
+    #[test]
+    fn test_weighted_star_classes() {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let mut m_dwarfs: u32 = 0;
+        let mut black_holes: u32 = 0;
+        for _ in 1..=10_000 {
+            match create_random_star_from_rng(&mut rng).stats().label.as_ref() {
+                "M" => m_dwarfs += 1,
+                "BH" => black_holes += 1,
+                _ => {},
+            };
+        };
+        assert!(m_dwarfs > 100 * black_holes.max(1));
+
+        let weights: StarClassWeights = StarClassWeights::new(vec![("BH", 1.0), ("M", 0.0)]).unwrap();
+        let star: Star = Star::from_weights(String::from("Sgr A*"), &weights, &mut rng);
+        assert_eq!(star.stats().label, "BH");
+
+        let weights: StarClassWeights = StarClassWeights::default().with_weight("G", 0.0).unwrap();
+        assert_eq!(weights.weight_of("G"), Some(0.0));
+        assert!(StarClassWeights::new(vec![("G", -1.0)]).is_err());
+    }
+
+    #[test]
+    fn test_weighted_planet_types() {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let mut rocky: u32 = 0;
+        let mut earth_like: u32 = 0;
+        for _ in 1..=10_000 {
+            match create_random_planet_from_rng(&mut rng).stats().label.as_ref() {
+                "RKB" | "RKB(R)" => rocky += 1,
+                "ELW" | "ELW(R)" => earth_like += 1,
+                _ => {},
+            };
+        };
+        assert!(rocky > 100 * earth_like.max(1));
+
+        let black_hole: Star = create_named_star(String::from("Sgr A*"), "BH");
+        let sun: Star = create_named_star(String::from("Sol"), "G");
+        let around_bh: PlanetTypeWeights = PlanetTypeWeights::for_star(&black_hole);
+        let around_sun: PlanetTypeWeights = PlanetTypeWeights::for_star(&sun);
+        assert!(around_bh.weight_of("ELW") < around_sun.weight_of("ELW"));
+
+        let weights: PlanetTypeWeights = PlanetTypeWeights::new(vec![("AW", 1.0)]).unwrap();
+        let planet: Planet = Planet::from_weights(String::from("Ammonia"), &weights, &mut rng);
+        assert!(planet.stats().label.starts_with("AW"));
+    }
+
+    #[test]
+    fn test_parse_identifiers() {
+        assert!("AE/BE".parse::<StarClass>().is_ok());
+        let star: Star = Star::try_new(String::from("Sol"), "AEBE").unwrap();
+        assert_eq!(star.stats().label, "AE/BE");
+        assert!(Star::try_new(String::from("Sol"), "random").is_ok());
+
+        let err: ParseError = "GX".parse::<StarClass>().unwrap_err();
+        assert_eq!(err.kind(), "StarClass");
+        assert_eq!(err.input(), "GX");
+        assert!(err.to_string().contains("'GX'"));
+        assert!(err.to_string().contains("DAZ"));
+
+        assert!(PlanetType::try_from("ELW").is_ok());
+        let err: ParseError = Planet::try_new(String::from("Earth"), "EWL").unwrap_err();
+        assert_eq!(err.valid_identifiers(), &bodies::planets::PLANET_TYPE_IDENTIFIERS);
+
+        assert!(matches!("VR".parse::<Rarity>(), Ok(Rarity::VeryRare)));
+        assert!(Rarity::try_from("Mythic").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let sun: Star = create_seeded_star(0x501);
+        let json: String = serde_json::to_string(&sun).unwrap();
+        let restored: Star = serde_json::from_str(&json).unwrap();
+        assert_eq!(sun.name, restored.name);
+        assert_eq!(sun.stats(), restored.stats());
+
+        let earth: Planet = create_seeded_planet(0xea57);
+        let json: String = serde_json::to_string(&earth).unwrap();
+        let restored: Planet = serde_json::from_str(&json).unwrap();
+        assert_eq!(earth.name, restored.name);
+        assert_eq!(earth.stats(), restored.stats());
+
+        let stats_json: String = serde_json::to_string(&earth.stats()).unwrap();
+        assert!(stats_json.contains("\"orbital_period\":"));
+
+        let system: StarSystem = create_seeded_system(0x5e5);
+        let json: String = serde_json::to_string(&system).unwrap();
+        let restored: StarSystem = serde_json::from_str(&json).unwrap();
+        assert_eq!(system.name, restored.name);
+        assert_eq!(system.body_count(), restored.body_count());
+        assert_eq!(system.barycentres().count(), restored.barycentres().count());
+        let rarity: Rarity = serde_json::from_str("\"Legendary\"").unwrap();
+        assert!(matches!(rarity, Rarity::Legendary));
+    }
+
+    #[test]
+    fn test_owned_bodies() {
+        fn assert_owned<T: Clone + Send + Sync + 'static>() {}
+        assert_owned::<Star>();
+        assert_owned::<Planet>();
+        assert_owned::<StarSystem>();
+        assert_owned::<bodies::stars::Stats>();
+        assert_owned::<bodies::planets::Stats>();
+
+        let class: String = String::from("K");
+        let star: Star = create_named_star(String::from("Alpha Centauri B"), &class);
+        drop(class);
+        let handle = std::thread::spawn(move || star.stats());
+        assert_eq!(handle.join().unwrap().label, "K");
+
+        let bodies: Vec<Planet> = (0..3).map(|_| create_random_planet()).collect();
+        let copies: Vec<Planet> = bodies.clone();
+        for (p, q) in bodies.iter().zip(copies.iter()) {
+            assert_eq!(p.stats(), q.stats());
+        };
+    }
+
+    #[test]
+    fn test_planet_gravity_consistency() {
+        for _ in 1..=10_000 {
+            let stats = create_random_planet().stats();
+            let expected: f64 = stats.earth_masses / (stats.radius / EARTH_RADIUS_KM).powi(2);
+            assert!((stats.gravity - expected).abs() <= expected * 1e-9);
+        };
+
+        assert_eq!(surface_gravity(1.0, EARTH_RADIUS_KM), 1.0);
+        assert_eq!(surface_gravity(4.0, 2.0 * EARTH_RADIUS_KM), 1.0);
+    }
+
+    #[test]
+    fn test_stellar_luminosity() {
+        let mut sun: Star = create_named_star(String::from("Sol"), "G");
+        if let StarClass::G(ref mut info) = sun.class {
+            info.solar_radius = 1.0;
+            info.surface_temp = 5_772;
+        };
+        assert!((sun.luminosity() - 1.0).abs() < 1e-12);
+        assert!((sun.absolute_magnitude() - 4.74).abs() < 1e-12);
+        assert_eq!(sun.spectral_type(), "G2 V");
+        assert_eq!(sun.stats().spectral_type, "G2 V");
+
+        for _ in 1..=1000 {
+            let stats = create_named_star(String::from("Arcturus"), "KG").stats();
+            assert!(stats.spectral_type.starts_with('K'));
+            assert!(stats.spectral_type.ends_with(" III") || stats.spectral_type.ends_with(" I"));
+            assert!(stats.luminosity > 0.0);
+            let dwarf = create_named_star(String::from("Sirius B"), "DA").stats();
+            assert!(dwarf.spectral_type.starts_with("DA"));
+        };
+    }
+
+    #[test]
+    fn test_habitable_zone() {
+        let mut sun: Star = create_named_star(String::from("Sol"), "G");
+        if let StarClass::G(ref mut info) = sun.class {
+            info.solar_radius = 1.0;
+            info.surface_temp = 5_772;
+        };
+        let (inner, outer): (f64, f64) = sun.habitable_zone_au();
+        assert!(inner < 1.0 && outer > 1.0);
+        assert!((sun.frost_line_au() - 2.7).abs() < 1e-12);
+        assert!((sun.frost_line_ls() - 2.7 * AU_LS).abs() < 1e-9);
+
+        let mut rng: StdRng = StdRng::seed_from_u64(1);
+        let (inner, outer): (f64, f64) = sun.habitable_zone_ls();
+        for _ in 1..=100 {
+            let mut earth: Planet = create_named_planet(String::from("Earth"), "ELW");
+            earth.place_around(&sun, &mut rng);
+            let dist: f64 = earth.stats().dist_from_arrival;
+            assert!(dist >= inner && dist <= outer);
+
+            let mut ice: Planet = create_named_planet(String::from("Pluto"), "ICB");
+            assert_eq!(ice.stats().dist_from_arrival, 0.0);
+            assert_eq!(ice.stats().orbital_period, Duration::ZERO);
+            let mut farther: Planet = ice.clone();
+            ice.place_around(&sun, &mut rng);
+            farther.place_around(&sun, &mut rng);
+            let (near, far): (bodies::planets::Stats, bodies::planets::Stats) = (ice.stats(), farther.stats());
+            assert!(near.dist_from_arrival >= sun.frost_line_ls());
+            assert_eq!(near.orbital_period, near.orbit.unwrap().period());
+
+            // Surface temperature follows the star's light, falling with the square root of distance.
+            let near_flux: f64 = near.surface_temp * near.dist_from_arrival.sqrt();
+            let far_flux: f64 = far.surface_temp * far.dist_from_arrival.sqrt();
+            assert!((near_flux - far_flux).abs() < 1e-9 * near_flux);
+            for stats in [&near, &far] {
+                assert_eq!(stats.landable, stats.surface_pressure <= 4.2 && stats.surface_temp <= 666.0);
+                assert_eq!(stats.materials.is_empty(), !stats.landable);
+            };
+        };
+    }
+
+    #[test]
+    fn test_keplerian_period() {
+        let earth_orbit = OrbitalElements {
+            semi_major_axis: AU_LS,
+            eccentricity: 0.0167,
+            inclination: 0.0,
+            arg_of_periapsis: 102.9,
+            ascending_node: 0.0,
+            mean_anomaly: 358.6,
+            system_mass: 1.0 + 1.0 / EARTH_MASSES_PER_SOLAR_MASS,
+        };
+        let days: f64 = earth_orbit.period().as_secs_f64() / 86_400.0;
+        assert!((days - 365.25).abs() < 0.1);
+
+        let sun: Star = create_named_star(String::from("Sol"), "G");
+        let mut rng: StdRng = StdRng::seed_from_u64(2);
+        let planet: Planet = Planet::from_star(String::from("Earth"), &sun, &mut rng);
+        let stats = planet.stats();
+        let orbit: OrbitalElements = stats.orbit.unwrap();
+        assert_eq!(orbit.semi_major_axis, stats.dist_from_arrival);
+        assert_eq!(orbit.period(), stats.orbital_period);
+        assert!(orbit.periapsis() <= orbit.apoapsis());
+        assert!(create_random_planet().stats().orbit.is_none());
+    }
+
+    #[test]
+    fn test_ephemeris() {
+        let orbit = OrbitalElements {
+            semi_major_axis: 500.0,
+            eccentricity: 0.4,
+            inclination: 30.0,
+            arg_of_periapsis: 45.0,
+            ascending_node: 120.0,
+            mean_anomaly: 10.0,
+            system_mass: 1.0,
+        };
+        let period: f64 = orbit.period().as_secs_f64();
+        for step in 0..100 {
+            let t: f64 = period * step as f64 / 100.0;
+            let r: f64 = orbit.position_at(t).magnitude();
+            assert!(r >= orbit.periapsis() - 1e-6 && r <= orbit.apoapsis() + 1e-6);
+            assert!(orbit.position_at(t).distance(&orbit.position_at(t + period)) < 1e-6);
+
+            // Vis-viva: v² = μ(2/r - 1/a)
+            let mu: f64 = orbit.gravitational_parameter();
+            let expected: f64 = (mu * (2.0 / (r * LIGHT_SECOND_M) - 1.0 / (500.0 * LIGHT_SECOND_M))).sqrt() / 1_000.0;
+            assert!((orbit.velocity_at(t).magnitude() - expected).abs() < 1e-6 * expected);
+        };
+
+        let system: StarSystem = create_seeded_system(7);
+        let t: f64 = 86_400.0 * 42.0;
+        let names: Vec<String> = system.stars().map(|s| s.name.clone())
+            .chain(system.planets.iter().map(|p| p.name.clone()))
+            .collect();
+        for name in &names {
+            assert!(system.position_of(name, t).is_some());
+            assert!(system.velocity_of(name, t).is_some());
+        };
+        let (first, last) = (names.first().unwrap(), names.last().unwrap());
+        assert_eq!(system.distance_between(first, last, t), system.distance_between(last, first, t));
+        assert!(system.position_of("nowhere", t).is_none());
+        assert_eq!(system.primary_star.position_at(t).is_none(), system.companion_stars.is_empty());
+    }
+
+    #[test]
+    fn test_seeded_system() {
+        let a: StarSystem = create_seeded_system(1337);
+        let b: StarSystem = create_seeded_system(1337);
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.body_count(), b.body_count());
+        assert_eq!(a.primary_star.stats(), b.primary_star.stats());
+        for (p, q) in a.planets.iter().zip(b.planets.iter()) {
+            assert_eq!(p.name, q.name);
+            assert_eq!(p.stats(), q.stats());
+        };
+    }
+
+    #[test]
+    fn test_moons() {
+        let mut moon_count: usize = 0;
+        for seed in 0..200 {
+            let system: StarSystem = create_seeded_system(seed);
+            for planet in &system.planets {
+                let hill_limit: f64 = planet.hill_radius_ls().unwrap() * MOON_HILL_FRACTION;
+                let mut last_dist: f64 = 0.0;
+                for moon in &planet.moons {
+                    let orbit: OrbitalElements = moon.stats().orbit.unwrap();
+                    assert!(orbit.periapsis() >= planet.roche_limit_ls(moon.density()) * (1.0 - 1e-9));
+                    assert!(orbit.apoapsis() <= hill_limit * (1.0 + 1e-9));
+                    assert!(orbit.semi_major_axis >= last_dist);
+                    assert!(moon.stats().earth_masses <= planet.stats().earth_masses * MAX_MOON_MASS_RATIO);
+                    assert!(moon.name.starts_with(&planet.name));
+                    assert!(moon.moons.is_empty());
+                    last_dist = orbit.semi_major_axis;
+                };
+                moon_count += planet.moons.len();
+            };
+            assert_eq!(system.moons().count(), system.planets.iter().map(|p| p.moons.len()).sum::<usize>());
+        };
+        assert!(moon_count > 0);
+        assert!(create_random_planet().moons.is_empty());
+    }
+
+    #[test]
+    fn test_ring_systems() {
+        let mut rng: StdRng = StdRng::seed_from_u64(14);
+        let mut ringed_count: usize = 0;
+        for _ in 0..20_000 {
+            let planet: Planet = create_random_planet_from_rng(&mut rng);
+            let stats = planet.stats();
+            assert_eq!(stats.label.ends_with("(R)"), stats.rings.is_some());
+            let Some(rings) = stats.rings else {
+                continue;
+            };
+            ringed_count += 1;
+            assert!(!rings.rings.is_empty());
+            assert!(rings.inner_radius() > stats.radius);
+            let density: f64 = bulk_density(stats.earth_masses, stats.radius);
+            for ring in &rings.rings {
+                assert!(ring.inner_radius < ring.outer_radius);
+                assert!(ring.outer_radius <= roche_limit(stats.radius, density, ring.class.density()));
+                assert!(ring.mass > 0.0);
+            };
+            assert!(rings.rings.windows(2).all(|w| w[0].outer_radius < w[1].inner_radius));
+        };
+        assert!(ringed_count > 0);
+
+        let star: Star = Star::from_seed(String::from("Ringed"), "Y", 3);
+        assert_eq!(star.stats().class_name.starts_with("Ringed"), star.stats().rings.is_some());
+    }
+
+    #[test]
+    fn test_asteroid_belts() {
+        let mut young_belts: usize = 0;
+        let mut remnant_belts: usize = 0;
+        for seed in 0..2_000 {
+            let star: Star = Star::from_seed(String::from("Belter"), "TTS", seed);
+            assert_eq!(star.stats().belts, star.belts);
+            assert!(star.belts.len() <= 2);
+            for belt in &star.belts {
+                assert!(belt.inner_radius < belt.outer_radius);
+                assert!(belt.inner_radius > star.class.info().solar_radius * SOLAR_RADIUS_LS);
+                assert!(belt.mass > 0.0);
+                assert!(belt.name.starts_with("Belter ") && belt.name.ends_with(" Belt"));
+            };
+            assert!(star.belts.windows(2).all(|w| w[0].inner_radius < w[1].inner_radius));
+            young_belts += star.belts.len();
+            remnant_belts += Star::from_seed(String::from("Husk"), "DA", seed).belts.len();
+        };
+        assert!(young_belts > remnant_belts);
+    }
+
+    #[test]
+    fn test_star_hierarchy() {
+        let mut multiples: usize = 0;
+        for seed in 0..500 {
+            let system: StarSystem = create_seeded_system(seed);
+            let star_count: usize = system.stars().count();
+            assert_eq!(system.hierarchy.star_indices().len(), star_count);
+            assert_eq!(system.hierarchy.walk().count(), 2 * star_count - 1);
+            assert_eq!(system.barycentres().count(), star_count - 1);
+            if star_count == 1 {
+                continue;
+            };
+            multiples += 1;
+            assert!(system.hierarchy.find(&format!("{} AB", system.name)).is_some());
+
+            let stars: Vec<&Star> = system.stars().collect();
+            let t: f64 = 1_000_000.0 * seed as f64;
+            for barycentre in system.barycentres() {
+                assert!(barycentre.mass_ratio() > 0.0);
+                assert!((barycentre.mass - barycentre.primary.mass() - barycentre.secondary.mass()).abs() < 1e-9);
+
+                // Both members share the pair's period and balance each other about the barycentre.
+                let mut moment: Vector3 = Vector3::default();
+                for member in [&barycentre.primary, &barycentre.secondary] {
+                    let orbit: OrbitalElements = match member {
+                        OrbitalNode::Star { index, .. } => stars[*index].stats().orbit.unwrap(),
+                        OrbitalNode::Barycentre(inner) => {
+                            assert!(inner.separation.apoapsis() < barycentre.separation.semi_major_axis);
+                            inner.orbit.unwrap()
+                        },
+                    };
+                    assert!((orbit.period().as_secs_f64() / barycentre.period().as_secs_f64() - 1.0).abs() < 1e-9);
+                    moment = moment + orbit.position_at(t) * member.mass();
+                };
+                assert!(moment.magnitude() < 1e-6 * barycentre.mass * barycentre.separation.apoapsis());
+            };
+        };
+        assert!(multiples > 0);
+    }
+
+    #[test]
+    fn test_procgen_names() {
+        // Every sector in the grid has a distinct name that maps back to it.
+        let (nx, ny, nz) = naming::SECTOR_GRID;
+        for k in 0..nx * ny * nz {
+            let sector: (u32, u32, u32) = (k % nx, (k / nx) % ny, k / (nx * ny));
+            assert_eq!(sector_coordinates(&sector_name(sector)), Some(sector));
+        };
+
+        let mut rng: StdRng = StdRng::seed_from_u64(0x5ec7);
+        for _ in 0..10_000 {
+            let position: Coordinates = Coordinates::new(
+                rng.gen_range(-25_000.0..25_000.0),
+                rng.gen_range(-10_000.0..10_000.0),
+                rng.gen_range(-12_000.0..38_000.0),
+            );
+            let mass_code: MassCode = MASS_CODES[rng.gen_range(0..MASS_CODES.len())];
+            let name: ProcGenName = ProcGenName::from_position(position, mass_code, rng.gen_range(0..1000)).unwrap();
+
+            let parsed: ProcGenName = name.to_string().parse().unwrap();
+            assert_eq!(parsed, name);
+            let centre: Coordinates = parsed.approximate_position().unwrap();
+            assert!(centre.distance(&position) <= mass_code.boxel_size() * 3.0_f64.sqrt() / 2.0);
+        };
+
+        let sol: ProcGenName = ProcGenName::from_position(Coordinates::default(), MassCode::D, 7).unwrap();
+        assert_eq!(sol.to_string().parse::<ProcGenName>().unwrap().mass_code, MassCode::D);
+        assert!("Sol".parse::<ProcGenName>().is_err());
+        assert!("Qwerty AB-C d1-2".parse::<ProcGenName>().is_err());
+        assert!(format!("{} AB-C h1-2", sol.sector).parse::<ProcGenName>().is_err());
+        // Boxel numbers past the end of the sector, or of a u32, are rejected rather than overflowing.
+        assert!(format!("{} AB-C a999999-1", sol.sector).parse::<ProcGenName>().is_err());
+        assert!(format!("{} ZZ-Z a4294967295-1", sol.sector).parse::<ProcGenName>().is_err());
+        assert!(create_seeded_star(0x501).name.parse::<ProcGenName>().is_ok());
+    }
+
+    #[test]
+    fn test_galaxy() {
+        let galaxy: Galaxy = Galaxy::new(0x9a1a);
+        let sol: Coordinates = Coordinates::default();
+        assert!(galaxy.stellar_density(GALACTIC_CORE) > galaxy.stellar_density(sol));
+        assert!(galaxy.stellar_density(sol) > galaxy.stellar_density(Coordinates::new(0.0, 5_000.0, 0.0)));
+
+        // The same region always yields the same systems, each inside its own boxel.
+        let nearby: Vec<GalacticSystem> = galaxy.systems_near(sol, 40.0);
+        assert_eq!(nearby, Galaxy::new(0x9a1a).systems_near(sol, 40.0));
+        assert_ne!(nearby, Galaxy::new(0x9a1b).systems_near(sol, 40.0));
+        assert!(nearby.len() > 100);
+        for system in nearby.iter() {
+            assert!(system.position.distance(&sol) <= 40.0);
+            let boxel: Boxel = system.name.region().unwrap();
+            assert_eq!(Boxel::containing(system.position, boxel.mass_code), Some(boxel));
+            assert_eq!(galaxy.find(&system.name).as_ref(), Some(system));
+        };
+
+        for system in nearby.iter().take(5) {
+            let generated: StarSystem = system.generate();
+            assert_eq!(generated.name, system.name.to_string());
+            assert_eq!(MassCode::for_solar_masses(generated.primary_star.class.info().solar_masses), system.name.mass_code);
+        };
+    }
+
+    #[test]
+    fn test_scan_values() {
+        let plain: ScanOptions = ScanOptions::default();
+        let discovered: ScanOptions = ScanOptions { first_discovery: true, ..plain };
+        let mapped: ScanOptions = ScanOptions { mapped: true, ..plain };
+
+        let sun: Star = Star::from_seed(String::from("Sol"), "G", 0x5eed);
+        let dwarf: Star = Star::from_seed(String::from("Sirius B"), "DA", 0x5eed);
+        assert!(dwarf.scan_value(&plain) > sun.scan_value(&plain));
+        assert_eq!(sun.scan_value(&mapped), sun.scan_value(&plain));
+        let ratio: f64 = sun.scan_value(&discovered) as f64 / sun.scan_value(&plain) as f64;
+        assert!((ratio - FIRST_DISCOVERY_MULTIPLIER).abs() < 1e-3);
+
+        let earth: Planet = Planet::from_seed(String::from("Earth"), "ELW", 0x5eed);
+        assert!(earth.scan_value(&mapped) > 3 * earth.scan_value(&plain));
+        assert!(earth.scan_value(&ScanOptions::best()) > earth.scan_value(&mapped));
+
+        let rock: Planet = Planet::from_seed(String::from("Rock"), "ICB", 0x5eed);
+        assert!(rock.scan_value(&plain) as f64 >= MIN_SCAN_VALUE);
+
+        let system: StarSystem = create_seeded_system(0x5eed);
+        let total: u64 = system.stars().map(|s| s.scan_value(&plain)).sum::<u64>()
+            + system.planets.iter().chain(system.moons()).map(|p| p.scan_value(&plain)).sum::<u64>();
+        assert_eq!(system.scan_value(&plain), total);
+    }
+
+    #[test]
+    fn test_terraforming() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0x7e22a);
+        let mut candidates: u32 = 0;
+        for ptype in ["WW", "HMC", "RKB", "MRB", "ELW", "ICB"] {
+            for _ in 0..20_000 {
+                let planet: Planet = Planet::from_rng(String::from("Mars"), ptype, &mut rng);
+                let stats: bodies::planets::Stats = planet.stats();
+                assert_eq!(stats.type_name.starts_with(stats.terraform_state.fetch_state()), stats.terraform_state.is_terraformable());
+                if !stats.terraform_state.is_terraformable() {
+                    continue;
+                };
+                assert!(["WW", "HMC", "RKB", "MRB"].contains(&ptype));
+                assert!((180.0..=420.0).contains(&stats.surface_temp) && (0.1..=2.5).contains(&stats.gravity));
+                candidates += u32::from(stats.terraform_state == TerraformState::Candidate);
+
+                // Terraformable planets are worth more than the same planet without the potential.
+                let mut barren: Planet = planet.clone();
+                barren.ptype.info_mut().terraform_state = TerraformState::NotTerraformable;
+                assert!(planet.scan_value(&ScanOptions::default()) > barren.scan_value(&ScanOptions::default()));
+            };
+        };
+        assert!(candidates > 0);
+    }
+
+    #[test]
+    fn test_atmospheres() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0xa7305);
+        for _ in 0..2_000 {
+            let ammonia: Planet = Planet::from_rng(String::from("Ammonia"), "AW", &mut rng);
+            let earth: Planet = Planet::from_rng(String::from("Earth"), "ELW", &mut rng);
+            for (planet, expected) in [(&ammonia, AtmosphereType::Ammonia), (&earth, AtmosphereType::NitrogenOxygen)] {
+                if let Some(atmosphere) = planet.stats().atmosphere {
+                    assert_eq!(atmosphere.atmosphere_type, expected);
+                };
+            };
+        };
+
+        let thick: Atmosphere = Atmosphere::random("AW", 200.0, 42.0, 1.0, &mut rng).unwrap();
+        assert_eq!(thick.density, AtmosphereDensity::Thick);
+        assert_eq!(thick.to_string(), "Thick Ammonia");
+        assert!(thick.share_of(Gas::Ammonia) > 50.0);
+        assert!(Atmosphere::random("RKB", 300.0, 0.0, 1.0, &mut rng).is_none());
+        let hot: Atmosphere = Atmosphere::random("MRB", 2_000.0, 1.0, 1.0, &mut rng).unwrap();
+        assert_eq!(hot.density, AtmosphereDensity::Normal);
+        assert!(hot.composition[0].0 != Gas::Methane && hot.composition[0].0 != Gas::Neon);
+
+        let system: StarSystem = create_seeded_system(0xa7305);
+        for planet in system.planets.iter().chain(system.moons()) {
+            let stats: bodies::planets::Stats = planet.stats();
+            let Some(atmosphere) = stats.atmosphere else {
+                continue;
+            };
+            let total: f64 = atmosphere.composition.iter().map(|(_, share)| share).sum();
+            assert!((total - 100.0).abs() < 1e-6);
+            assert!(atmosphere.composition.windows(2).all(|w| w[0].1 >= w[1].1));
+            assert_eq!(atmosphere.density, AtmosphereDensity::from_pressure(stats.surface_pressure));
+            if stats.gravity < MIN_LIGHT_GAS_GRAVITY {
+                assert!(atmosphere.composition.iter().all(|(gas, _)| !gas.is_light()));
+            };
+        };
+    }
+
+    #[test]
+    fn test_volcanism() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0x7010);
+        let mut active: u32 = 0;
+        for _ in 0..2_000 {
+            let giant: Planet = Planet::from_rng(String::from("Jupiter"), "CIGG", &mut rng);
+            assert!(giant.stats().volcanism.is_none());
+
+            for ptype in ["ICB", "MRB"] {
+                let planet: Planet = Planet::from_rng(String::from("Io"), ptype, &mut rng);
+                let stats: bodies::planets::Stats = planet.stats();
+                let Some(volcanism) = stats.volcanism else {
+                    continue;
+                };
+                active += 1;
+                if volcanism.volcanism_type.is_cryovolcanic() {
+                    assert!(stats.surface_temp < 373.0);
+                };
+                if ptype == "MRB" {
+                    assert!(!volcanism.volcanism_type.is_cryovolcanic());
+                };
+            };
+        };
+        assert!(active > 0);
+
+        // Frozen volatiles are the only thing a frigid icy body can erupt.
+        for _ in 0..100 {
+            if let Some(volcanism) = Volcanism::random("ICB", 1.0, 60.0, &mut rng) {
+                assert!(volcanism.volcanism_type.is_cryovolcanic());
+            };
+        };
+
+        let geysers: Volcanism = Volcanism {
+            volcanism_type: VolcanismType::Silicate,
+            eruption: Eruption::Geysers,
+            intensity: VolcanismIntensity::Major,
+        };
+        assert_eq!(geysers.to_string(), "Major Silicate Vapour Geysers");
+        let magma: Volcanism = Volcanism { volcanism_type: VolcanismType::Water, eruption: Eruption::Magma, intensity: VolcanismIntensity::Normal };
+        assert_eq!(magma.to_string(), "Water Magma");
+    }
+
+    #[test]
+    fn test_moon_temperatures() {
+        // Moons share their parent's distance from the star, so are warmed by the same starlight.
+        let mut moons: u32 = 0;
+        for seed in 0..50 {
+            let system: StarSystem = create_seeded_system(seed);
+            let luminosity: f64 = system.primary_star.luminosity();
+            for planet in system.planets.iter() {
+                let parent: bodies::planets::Stats = planet.stats();
+                for moon in planet.moons.iter() {
+                    let stats: bodies::planets::Stats = moon.stats();
+                    let expected: f64 = surface_temperature(luminosity, parent.dist_from_arrival, stats.surface_pressure);
+                    assert_eq!(stats.dist_from_arrival, parent.dist_from_arrival);
+                    assert!((stats.surface_temp - expected).abs() <= 1e-9 * expected);
+                    assert_eq!(stats.landable, stats.surface_pressure <= 4.2 && stats.surface_temp <= 666.0);
+                    moons += 1;
+                };
+            };
+        };
+        assert!(moons > 0);
+    }
+
+    #[test]
+    fn test_moon_volcanism() {
+        // Moons are shrunk after generation, so their volcanism must follow their final, much lower mass.
+        let (mut tiny, mut tiny_active, mut heavy, mut heavy_active): (u32, u32, u32, u32) = (0, 0, 0, 0);
+        for seed in 0..300 {
+            let system: StarSystem = create_seeded_system(seed);
+            for moon in system.moons() {
+                let stats: bodies::planets::Stats = moon.stats();
+                if stats.earth_masses < 0.002 {
+                    tiny += 1;
+                    tiny_active += stats.volcanism.is_some() as u32;
+                }else if stats.earth_masses > 0.1 {
+                    heavy += 1;
+                    heavy_active += stats.volcanism.is_some() as u32;
+                };
+            };
+        };
+        assert!(tiny > 0 && heavy > 0);
+        // The lightest bodies keep a fifth of the base activity chance, topping out at 10% for Earth-like worlds.
+        assert!((tiny_active as f64) < 0.1 * tiny as f64);
+        assert!((tiny_active as f64) / (tiny as f64) < (heavy_active as f64) / (heavy as f64));
+    }
+
+    #[test]
+    fn test_bulk_composition() {
+        assert!((EARTH_COMPOSITION.radius_factor() - 1.0).abs() < 1e-12);
+
+        let mut rng: StdRng = StdRng::seed_from_u64(0xc0e);
+        for _ in 0..1_000 {
+            for ptype in ["RKB", "ICB", "HMC", "MRB", "RIW", "ELW", "WW", "AW"] {
+                let composition: Composition = Planet::from_rng(String::from("Ceres"), ptype, &mut rng).stats().composition.unwrap();
+                assert!((composition.ice + composition.rock + composition.metal - 100.0).abs() < 1e-9);
+                assert!(composition.ice >= 0.0 && composition.rock >= 0.0 && composition.metal >= 0.0);
+                match ptype {
+                    "MRB" => assert!(composition.metal > 50.0),
+                    "ICB" => assert!(composition.ice > 50.0),
+                    _ => {},
+                };
+            };
+            assert!(Planet::from_rng(String::from("Saturn"), "CIGG", &mut rng).stats().composition.is_none());
+        };
+
+        // At equal mass, an icy body is larger and less dense than a metal-rich one.
+        let icy: Planet = Planet::from_seed(String::from("Icy"), "ICB", 0xc0e);
+        let metallic: Planet = Planet::from_seed(String::from("Metallic"), "MRB", 0xc0e);
+        let (icy, metallic): (bodies::planets::Stats, bodies::planets::Stats) = (icy.stats(), metallic.stats());
+        let icy_radius_at_metallic_mass: f64 = icy.radius * (metallic.earth_masses / icy.earth_masses).powf(0.279);
+        assert!(icy_radius_at_metallic_mass > metallic.radius);
+        assert!(icy.composition.unwrap().density() < metallic.composition.unwrap().density());
+    }
+
+    #[test]
+    fn test_surface_materials() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0x3a7);
+        let metallic: Composition = Composition { ice: 0.0, rock: 15.0, metal: 85.0 };
+        let icy: Composition = Composition { ice: 90.0, rock: 8.0, metal: 2.0 };
+        let (mut metal_on_metallic, mut metal_on_icy): (f64, f64) = (0.0, 0.0);
+        for _ in 0..500 {
+            for (ptype, composition) in [("MRB", &metallic), ("ICB", &icy)] {
+                let materials: Vec<(Material, f64)> = random_materials(ptype, composition, &mut rng);
+                assert!((6..=10).contains(&materials.len()));
+                assert!((materials.iter().map(|(_, share)| share).sum::<f64>() - 100.0).abs() < 1e-9);
+                assert!(materials.windows(2).all(|w| w[0].1 >= w[1].1 && w[0].0 != w[1].0));
+                let metal: f64 = materials.iter().filter(|(m, _)| m.host() == MaterialHost::Metal).map(|(_, share)| share).sum();
+                if ptype == "MRB" { metal_on_metallic += metal } else { metal_on_icy += metal };
+            };
+        };
+        assert!(metal_on_metallic > 2.0 * metal_on_icy);
+
+        let system: StarSystem = create_seeded_system(0x3a7);
+        for planet in system.planets.iter().chain(system.moons()) {
+            let stats: bodies::planets::Stats = planet.stats();
+            assert_eq!(!stats.materials.is_empty(), stats.landable && stats.composition.is_some());
+        };
+    }
+
+    #[test]
+    fn test_exobiology() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0xb10);
+        let star: Star = Star::new("Exo A".to_owned(), "G");
+        let mut planet: Planet = Planet::new("Exo A 1".to_owned(), "RKB");
+        {
+            let info = planet.ptype.info_mut();
+            info.explorable = true;
+            info.surface_temp = 185.0;
+            info.gravity = 0.1;
+            info.volcanism = None;
+            info.atmosphere = Some(Atmosphere {
+                atmosphere_type: AtmosphereType::CarbonDioxide,
+                density: AtmosphereDensity::Thin,
+                composition: vec![(Gas::CarbonDioxide, 100.0)],
+            });
+        };
+
+        let mut found: usize = 0;
+        for _ in 0..500 {
+            let species: Vec<Species> = random_species(planet.ptype.info(), &star, &mut rng);
+            assert!(species.len() <= MAX_GENERA);
+            assert!(species.windows(2).all(|w| w[0].name < w[1].name && w[0].genus != w[1].genus));
+            for s in species.iter() {
+                let info = SPECIES.iter().find(|info| info.name == s.name).expect("Species is catalogued");
+                assert!(info.can_live_on(planet.ptype.info(), &star));
+                assert_eq!(s.first_logged_value(), s.value * 5);
+            };
+            found += species.len();
+        };
+        assert!(found > 0);
+
+        planet.generate_species(&star, &mut rng);
+        let value: u64 = planet.stats().species.iter().map(|s| s.value).sum();
+        assert_eq!(planet.exobiology_value(false), value);
+
+        // Species that need volcanism, other gases or other stars never appear.
+        assert!(SPECIES.iter().filter(|info| !info.can_live_on(planet.ptype.info(), &star))
+            .any(|info| info.name == "Bacterium Tela" || info.name == "Electricae Pluma"));
+        planet.ptype.info_mut().atmosphere = None;
+        assert!(random_species(planet.ptype.info(), &star, &mut rng).is_empty());
+
+        let system: StarSystem = create_seeded_system(0xb10);
+        for planet in system.planets.iter().chain(system.moons()) {
+            let stats: bodies::planets::Stats = planet.stats();
+            assert!(stats.species.is_empty() || stats.explorable);
+        };
+    }
+
+    #[test]
+    fn test_planet_stability() {
+        let mut binaries: u32 = 0;
+        for seed in 0..500 {
+            let system: StarSystem = create_seeded_system(seed);
+            let limit: f64 = system.stable_limit();
+            assert_eq!(limit.is_finite(), !system.companion_stars.is_empty());
+            // Every barycentre the primary belongs to bounds its planets.
+            for barycentre in system.barycentres() {
+                if barycentre.primary.star_indices().contains(&0) || barycentre.secondary.star_indices().contains(&0) {
+                    binaries += 1;
+                    assert!(limit <= barycentre.separation.periapsis() * PLANET_STABILITY_FRACTION);
+                };
+            };
+            for planet in system.planets.iter() {
+                assert!(planet.stats().orbit.unwrap().apoapsis() <= limit);
+            };
+        };
+        assert!(binaries > 0);
+    }
+
+    #[test]
+    fn test_random_systems() {
+        for _ in 1..=1000 {
+            let system: StarSystem = create_random_system();
+            let distances: Vec<f64> = system.planets.iter().map(|p| p.stats().dist_from_arrival).collect();
+            assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+            assert_eq!(system.stars().count(), 1 + system.companion_stars.len());
+        };
+    }
+
     #[test]
     // #[ignore]
     fn test_random_planets() {
@@ -1,6 +1,6 @@
-use stellar_oxide::bodies::stars::Star;
 use stellar_oxide::bodies::planets::Planet;
-use stellar_oxide::{cls, wait, input, create_random_star, create_random_planet};
+use stellar_oxide::systems::star_systems::StarSystem;
+use stellar_oxide::{cls, wait, input, create_random_system};
 
 
 /* Current goal is to generate random star systems
@@ -32,19 +32,22 @@ fn main() {
         let mut iter: i32 = 0;
         loop {
             cls();
-            let star: Star = create_random_star();
-            let planet: Planet = create_random_planet();
+            let system: StarSystem = create_random_system();
             let answer_star: String = answer_star.trim().to_lowercase();
             let answer_planet: String = answer_planet.trim().to_lowercase();
             iter += 1;
-            if star.stats().label.to_lowercase() == answer_star && planet.stats().label.to_lowercase() == answer_planet {
+            let star_matches: bool = system.primary_star.stats().label.to_lowercase() == answer_star;
+            let planet: Option<&Planet> = system.planets.iter()
+                .find(|p| p.stats().label.to_lowercase() == answer_planet);
+            if let (true, Some(planet)) = (star_matches, planet) {
                 println!(" Generated requested combination: '{}' and '{}'.", answer_star.to_uppercase(), answer_planet.to_uppercase());
                 println!();
-                println!(" {:#?}", star);
+                println!(" {:#?}", system.primary_star);
                 for _ in 0..=17 { print!("-"); };
                 print!("| Iterations: {} |", iter);
                 for _ in 0..=18 { print!("-"); };
                 println!("\n {:#?}", planet);
+                println!("\n System '{}' contains {} bodies.", system.name, system.body_count());
                 print!("\n Press Enter to Continue...");
                 wait(1);
                 let _continue: String = input();
@@ -52,11 +55,13 @@ fn main() {
             }else {
                 println!(" Searching for combination: '{}' and '{}'...", answer_star.to_uppercase(), answer_planet.to_uppercase());
                 println!();
-                println!(" {:#?}", star);
+                println!(" {:#?}", system.primary_star);
                 for _ in 0..=17 { print!("-"); };
                 print!("| Iterations: {} |", iter);
                 for _ in 0..=18 { print!("-"); };
-                println!("\n {:#?}", planet);
+                for planet in system.planets.iter() {
+                    println!("\n {} ({})", planet.name, planet.stats().label);
+                };
                 wait(1);
             };
 
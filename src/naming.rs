@@ -0,0 +1,352 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::ParseError;
+
+use rand::Rng;
+
+
+/// Edge length of a sector, in light-years.
+pub const SECTOR_SIZE: f64 = 1_280.0;
+
+/// Number of sectors along each axis of the galactic grid (x, y, z).
+pub const SECTOR_GRID: (u32, u32, u32) = (40, 16, 40);
+
+/// Galactic coordinates of the corner of the sector grid closest to negative infinity on every axis.
+/// The grid spans the whole disc, with Sol near the middle in x and y and the core far out along z.
+pub const GRID_ORIGIN: Coordinates = Coordinates { x: -25_600.0, y: -10_240.0, z: -12_800.0 };
+
+/// The accepted shapes of a procedurally-generated system name, used when reporting a *ParseError*.
+pub const NAME_FORMATS: [&str; 2] = ["Sector AB-C d1-23", "Sector AB-C d23"];
+
+/// Word-initial consonant clusters, the first syllable of every sector name.
+const ONSETS: [&str; 16] = ["B", "Br", "C", "Ch", "D", "Dr", "F", "Fl", "G", "Gr", "K", "Kr", "Ph", "Pl", "Sk", "Th"];
+
+/// Consonant clusters joining the two vowels of a sector name.
+const MEDIALS: [&str; 16] = ["b", "ck", "d", "ff", "g", "l", "ll", "m", "n", "nd", "r", "rr", "s", "st", "t", "v"];
+
+/// Consonant clusters ending a sector name, including none at all.
+const CODAS: [&str; 4] = ["", "n", "r", "s"];
+
+const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+
+
+/// A position in the galaxy, in light-years from Sol.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Coordinates {
+    /// Returns a new instance of the *Coordinates* struct.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Coordinates { x, y, z }
+    }
+
+    /// Returns the distance in light-years between two positions.
+    pub fn distance(&self, other: &Coordinates) -> f64 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2)).sqrt()
+    }
+}
+
+
+/// The size class of a boxel, from 'a' (10 ly cubes) to 'h' (a whole 1280 ly sector).
+/// Heavier primary stars are catalogued in larger boxels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MassCode {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+}
+
+/// Every *MassCode*, from smallest to largest boxel.
+pub const MASS_CODES: [MassCode; 8] = [
+    MassCode::A, MassCode::B, MassCode::C, MassCode::D, MassCode::E, MassCode::F, MassCode::G, MassCode::H,
+];
+
+impl MassCode {
+    /// Returns the *MassCode* for its lowercase letter, or *None* if the letter is not in 'a'..='h'.
+    pub fn from_char(letter: char) -> Option<Self> {
+        MASS_CODES.get((letter as u32).wrapping_sub('a' as u32) as usize).copied()
+    }
+
+    /// Returns the mass code under which a primary star of the given mass is catalogued.
+    pub fn for_solar_masses(solar_masses: f64) -> Self {
+        const LIMITS: [f64; 7] = [0.08, 0.25, 0.5, 1.0, 2.0, 8.0, 30.0];
+        let code: usize = LIMITS.iter().take_while(|limit| solar_masses >= **limit).count();
+        MASS_CODES[code]
+    }
+
+    /// Returns the lowercase letter of the *MassCode*.
+    pub fn letter(&self) -> char {
+        char::from(b'a' + *self as u8)
+    }
+
+    /// Returns the edge length of a boxel of this size, in light-years.
+    pub fn boxel_size(&self) -> f64 {
+        10.0 * f64::from(1_u32 << *self as u32)
+    }
+
+    /// Returns the number of boxels of this size along each edge of a sector.
+    pub fn boxels_per_side(&self) -> u32 {
+        128 >> *self as u32
+    }
+}
+
+
+/// A procedurally-generated system name, e.g. "Drollin AB-C d1-23".
+/// The sector name and boxel code together pin the system down to a single cube of space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcGenName {
+    pub sector: String,
+    pub mass_code: MassCode,
+    pub boxel: u32, // x + 128y + 16384z, in boxels of the mass code's size from the sector corner
+    pub index: u32, // Number of the system within its boxel
+}
+
+impl ProcGenName {
+    /// Returns the name of the system at the given position, catalogued under the given mass code.
+    /// Returns *None* if the position lies outside the sector grid.
+    pub fn from_position(position: Coordinates, mass_code: MassCode, index: u32) -> Option<Self> {
+        Boxel::containing(position, mass_code).map(|boxel| boxel.name(index))
+    }
+
+    /// Returns the name of a random system somewhere in the sector grid, catalogued under the given mass code.
+    pub fn random<R: Rng + ?Sized>(mass_code: MassCode, rng: &mut R) -> Self {
+        let position: Coordinates = Coordinates {
+            x: GRID_ORIGIN.x + rng.gen_range(0.0..SECTOR_SIZE * f64::from(SECTOR_GRID.0)),
+            y: GRID_ORIGIN.y + rng.gen_range(0.0..SECTOR_SIZE * f64::from(SECTOR_GRID.1)),
+            z: GRID_ORIGIN.z + rng.gen_range(0.0..SECTOR_SIZE * f64::from(SECTOR_GRID.2)),
+        };
+        let index: u32 = rng.gen_range(0..=500);
+        Self::from_position(position, mass_code, index).expect("Random positions lie inside the sector grid")
+    }
+
+    /// Returns the *Boxel* the name places the system in.
+    /// Returns *None* if the sector name is not a valid generated name.
+    pub fn region(&self) -> Option<Boxel> {
+        Some(Boxel {
+            sector: sector_coordinates(&self.sector)?,
+            mass_code: self.mass_code,
+            number: self.boxel,
+        })
+    }
+
+    /// Returns the centre of the system's boxel, the best position estimate a name alone can give.
+    /// Returns *None* if the sector name is not a valid generated name.
+    pub fn approximate_position(&self) -> Option<Coordinates> {
+        self.region().map(|boxel| boxel.centre())
+    }
+}
+
+
+/// A cube of space within a sector, sized by its *MassCode*. Every system catalogued under
+/// that mass code whose position falls inside the cube shares the boxel's part of its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Boxel {
+    pub sector: (u32, u32, u32), // Grid coordinates of the containing sector
+    pub mass_code: MassCode,
+    pub number: u32, // x + 128y + 16384z, in boxels from the sector corner
+}
+
+impl Boxel {
+    /// Returns the *Boxel* of the given size containing a position, or *None* outside the sector grid.
+    pub fn containing(position: Coordinates, mass_code: MassCode) -> Option<Self> {
+        let sector: (u32, u32, u32) = sector_of(position)?;
+        let corner: Coordinates = sector_corner(sector);
+        let size: f64 = mass_code.boxel_size();
+        let last: u32 = mass_code.boxels_per_side() - 1;
+        let bx: u32 = (((position.x - corner.x) / size) as u32).min(last);
+        let by: u32 = (((position.y - corner.y) / size) as u32).min(last);
+        let bz: u32 = (((position.z - corner.z) / size) as u32).min(last);
+        Some(Boxel { sector, mass_code, number: bx + by * 128 + bz * 16_384 })
+    }
+
+    /// Returns the position of the boxel within its sector, in boxels along each axis.
+    pub fn offset(&self) -> (u32, u32, u32) {
+        (self.number % 128, (self.number / 128) % 128, self.number / 16_384)
+    }
+
+    /// Returns the edge length of the boxel in light-years.
+    pub fn size(&self) -> f64 {
+        self.mass_code.boxel_size()
+    }
+
+    /// Returns the coordinates of the boxel's corner closest to negative infinity on every axis.
+    pub fn corner(&self) -> Coordinates {
+        let corner: Coordinates = sector_corner(self.sector);
+        let (bx, by, bz) = self.offset();
+        Coordinates {
+            x: corner.x + f64::from(bx) * self.size(),
+            y: corner.y + f64::from(by) * self.size(),
+            z: corner.z + f64::from(bz) * self.size(),
+        }
+    }
+
+    /// Returns the coordinates of the centre of the boxel.
+    pub fn centre(&self) -> Coordinates {
+        let corner: Coordinates = self.corner();
+        let half: f64 = self.size() / 2.0;
+        Coordinates::new(corner.x + half, corner.y + half, corner.z + half)
+    }
+
+    /// Returns the name of the *index*-th system catalogued in the boxel.
+    pub fn name(&self, index: u32) -> ProcGenName {
+        ProcGenName {
+            sector: sector_name(self.sector),
+            mass_code: self.mass_code,
+            boxel: self.number,
+            index,
+        }
+    }
+}
+
+impl fmt::Display for ProcGenName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = |n: u32| char::from(b'A' + (n % 26) as u8);
+        let high: u32 = self.boxel / 17_576;
+        write!(
+            f,
+            "{} {}{}-{} {}",
+            self.sector,
+            letter(self.boxel),
+            letter(self.boxel / 26),
+            letter(self.boxel / 676),
+            self.mass_code.letter(),
+        )?;
+        if high > 0 {
+            write!(f, "{}-{}", high, self.index)
+        }else {
+            write!(f, "{}", self.index)
+        }
+    }
+}
+
+impl FromStr for ProcGenName {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_name(s).ok_or_else(|| ParseError::new("ProcGenName", s, &NAME_FORMATS))
+    }
+}
+
+impl TryFrom<&str> for ProcGenName {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+
+/* Naming Util Functions */
+
+/// Returns the grid coordinates of the sector containing the given position, or *None* outside the grid.
+pub fn sector_of(position: Coordinates) -> Option<(u32, u32, u32)> {
+    let axis = |value: f64, origin: f64, count: u32| -> Option<u32> {
+        let cell: f64 = ((value - origin) / SECTOR_SIZE).floor();
+        (cell >= 0.0 && cell < f64::from(count)).then_some(cell as u32)
+    };
+    Some((
+        axis(position.x, GRID_ORIGIN.x, SECTOR_GRID.0)?,
+        axis(position.y, GRID_ORIGIN.y, SECTOR_GRID.1)?,
+        axis(position.z, GRID_ORIGIN.z, SECTOR_GRID.2)?,
+    ))
+}
+
+/// Returns the galactic coordinates of a sector's corner closest to negative infinity on every axis.
+pub fn sector_corner(sector: (u32, u32, u32)) -> Coordinates {
+    Coordinates {
+        x: GRID_ORIGIN.x + f64::from(sector.0) * SECTOR_SIZE,
+        y: GRID_ORIGIN.y + f64::from(sector.1) * SECTOR_SIZE,
+        z: GRID_ORIGIN.z + f64::from(sector.2) * SECTOR_SIZE,
+    }
+}
+
+/// Returns the generated name of the sector at the given grid coordinates.
+/// Every sector in the grid has a distinct name built from an onset, a vowel, a medial cluster, a second vowel and a coda.
+pub fn sector_name(sector: (u32, u32, u32)) -> String {
+    let mut n: usize = (sector.0 + sector.1 * SECTOR_GRID.0 + sector.2 * SECTOR_GRID.0 * SECTOR_GRID.1) as usize;
+    let mut take = |radix: usize| {
+        let digit: usize = n % radix;
+        n /= radix;
+        digit
+    };
+    let coda: &str = CODAS[take(CODAS.len())];
+    let second: char = VOWELS[take(VOWELS.len())];
+    let medial: &str = MEDIALS[take(MEDIALS.len())];
+    let first: char = VOWELS[take(VOWELS.len())];
+    let onset: &str = ONSETS[take(ONSETS.len())];
+    format!("{}{}{}{}{}", onset, first, medial, second, coda)
+}
+
+/// Returns the grid coordinates of the sector with the given generated name, or *None* if no sector has that name.
+pub fn sector_coordinates(name: &str) -> Option<(u32, u32, u32)> {
+    // Vowels only ever appear singly and between consonant clusters, so the name splits unambiguously around them.
+    let vowels: Vec<(usize, char)> = name.char_indices().filter(|(_, c)| VOWELS.contains(c)).collect();
+    let [(first_at, first), (second_at, second)] = vowels[..] else {
+        return None;
+    };
+    let onset: usize = ONSETS.iter().position(|o| *o == &name[..first_at])?;
+    let medial: usize = MEDIALS.iter().position(|m| *m == &name[first_at + 1..second_at])?;
+    let coda: usize = CODAS.iter().position(|c| *c == &name[second_at + 1..])?;
+    let first: usize = VOWELS.iter().position(|v| *v == first)?;
+    let second: usize = VOWELS.iter().position(|v| *v == second)?;
+
+    let mut n: usize = onset;
+    n = n * VOWELS.len() + first;
+    n = n * MEDIALS.len() + medial;
+    n = n * VOWELS.len() + second;
+    n = n * CODAS.len() + coda;
+
+    let n: u32 = n as u32;
+    let (nx, ny, nz): (u32, u32, u32) = SECTOR_GRID;
+    (n < nx * ny * nz).then(|| (n % nx, (n / nx) % ny, n / (nx * ny)))
+}
+
+/// Parses "Sector AB-C d1-23" or "Sector AB-C d23" into its parts.
+fn parse_name(name: &str) -> Option<ProcGenName> {
+    let mut words = name.rsplitn(3, ' ');
+    let code: &str = words.next()?;
+    let letters: &str = words.next()?;
+    let sector: &str = words.next()?;
+    sector_coordinates(sector)?;
+
+    let letter = |c: u8| c.is_ascii_uppercase().then(|| u32::from(c - b'A'));
+    let [l1, l2, b'-', l3] = *letters.as_bytes() else {
+        return None;
+    };
+    let (l1, l2, l3): (u32, u32, u32) = (letter(l1)?, letter(l2)?, letter(l3)?);
+
+    let mut code_chars = code.chars();
+    let mass_code: MassCode = MassCode::from_char(code_chars.next()?)?;
+    let numbers: &str = code_chars.as_str();
+    let (high, index): (u32, u32) = match numbers.split_once('-') {
+        Some((high, index)) => (high.parse().ok()?, index.parse().ok()?),
+        None => (0, numbers.parse().ok()?),
+    };
+
+    // Boxel numbers too large for a u32 are as invalid as those outside the sector.
+    let boxel: u32 = high.checked_mul(17_576)?.checked_add(l1 + l2 * 26 + l3 * 676)?;
+    let per_side: u32 = mass_code.boxels_per_side();
+    if boxel % 128 >= per_side || (boxel / 128) % 128 >= per_side || boxel / 16_384 >= per_side {
+        return None;
+    };
+
+    Some(ProcGenName {
+        sector: sector.to_string(),
+        mass_code,
+        boxel,
+        index,
+    })
+}
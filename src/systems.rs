@@ -1 +1,2 @@
-pub mod star_systems;
\ No newline at end of file
+pub mod star_systems;
+pub mod hierarchy;
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::time::Duration;
+use crate::bodies::orbits::OrbitalElements;
+
+
+/// A node in the orbital hierarchy of a multiple-star system.
+/// Leaves are individual stars, and every other node is the barycentre of a pair of nodes orbiting one another.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrbitalNode {
+    Star {
+        index: usize, // Position of the star in *StarSystem::stars()*
+        name: String,
+        mass: f64, // Solar masses
+    },
+    Barycentre(Box<Barycentre>),
+}
+
+/// The shared centre of mass of two nodes, e.g. "AB" for a close binary or "ABC" for a third star orbiting it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Barycentre {
+    pub name: String,
+    pub mass: f64, // Solar masses, of every star beneath this node
+    pub primary: OrbitalNode,
+    pub secondary: OrbitalNode,
+    pub separation: OrbitalElements, // Orbit of the secondary relative to the primary
+    pub orbit: Option<OrbitalElements>, // Orbit about the parent barycentre, None at the root
+}
+
+impl Barycentre {
+    /// Returns the ratio of the secondary's mass to the primary's (q = m₂ / m₁).
+    pub fn mass_ratio(&self) -> f64 {
+        self.secondary.mass() / self.primary.mass()
+    }
+
+    /// Returns the period shared by both members of the pair.
+    pub fn period(&self) -> Duration {
+        self.separation.period()
+    }
+}
+
+impl OrbitalNode {
+    /// Returns the name of the star or barycentre.
+    pub fn name(&self) -> &str {
+        match self {
+            OrbitalNode::Star { name, .. } => name,
+            OrbitalNode::Barycentre(b) => &b.name,
+        }
+    }
+
+    /// Returns the combined mass of every star at or beneath this node, in solar masses.
+    pub fn mass(&self) -> f64 {
+        match self {
+            OrbitalNode::Star { mass, .. } => *mass,
+            OrbitalNode::Barycentre(b) => b.mass,
+        }
+    }
+
+    /// Returns the inner *Barycentre* if this node is one.
+    pub fn barycentre(&self) -> Option<&Barycentre> {
+        match self {
+            OrbitalNode::Star { .. } => None,
+            OrbitalNode::Barycentre(b) => Some(b),
+        }
+    }
+
+    /// Returns the primary and secondary children of a barycentre, or *None* for a star.
+    pub fn children(&self) -> Option<(&OrbitalNode, &OrbitalNode)> {
+        self.barycentre().map(|b| (&b.primary, &b.secondary))
+    }
+
+    /// Returns a depth-first iterator over this node and everything beneath it, paired with each node's depth.
+    /// Primaries are visited before secondaries.
+    pub fn walk(&self) -> Walk<'_> {
+        Walk { stack: vec![(0, self)] }
+    }
+
+    /// Returns the node with the given name at or beneath this node.
+    pub fn find(&self, name: &str) -> Option<&OrbitalNode> {
+        self.walk().map(|(_, node)| node).find(|node| node.name() == name)
+    }
+
+    /// Returns the *StarSystem::stars()* index of every star at or beneath this node.
+    pub fn star_indices(&self) -> Vec<usize> {
+        self.walk()
+            .filter_map(|(_, node)| match node {
+                OrbitalNode::Star { index, .. } => Some(*index),
+                OrbitalNode::Barycentre(_) => None,
+            })
+            .collect()
+    }
+}
+
+
+/// Depth-first iterator over an *OrbitalNode* tree, returned by *OrbitalNode::walk()*.
+pub struct Walk<'a> {
+    stack: Vec<(usize, &'a OrbitalNode)>,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (usize, &'a OrbitalNode);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, node) = self.stack.pop()?;
+        if let Some((primary, secondary)) = node.children() {
+            self.stack.push((depth + 1, secondary));
+            self.stack.push((depth + 1, primary));
+        };
+        Some((depth, node))
+    }
+}
@@ -1,10 +1,387 @@
-// use crate::stellar_bodies::Stars::Star;
+use std::ops::RangeInclusive;
+use crate::bodies::stars::{Star, StarClass, SOLAR_RADIUS_LS};
+use crate::bodies::planets::{Planet, PlanetTypeWeights};
+use crate::bodies::scans::ScanOptions;
+use crate::bodies::orbits::{OrbitalElements, Vector3, log_uniform, EARTH_MASSES_PER_SOLAR_MASS};
+use crate::systems::hierarchy::{Barycentre, OrbitalNode};
+use crate::naming::{MassCode, ProcGenName};
 
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+    thread_rng as rng,
+};
 
-// #[derive(Debug)]
-// pub struct StarSystem<'a> {
-//     name: &'a str,
-//     primary_star: Star,
-//     stars: Vec<Star>,
 
-// }
\ No newline at end of file
+/// Fraction of the innermost companion's closest approach within which planets can orbit the primary star.
+/// Beyond it, the companion's pull makes planetary orbits unstable.
+pub const PLANET_STABILITY_FRACTION: f64 = 1.0 / 3.0;
+
+/// Times a planet is re-placed around the primary star to fit inside the stable zone before it is dropped.
+const PLACEMENT_ATTEMPTS: u32 = 10;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StarSystem {
+    pub name: String,
+    pub primary_star: Star,
+    pub companion_stars: Vec<Star>,
+    pub hierarchy: OrbitalNode, // How the stars orbit one another
+    pub planets: Vec<Planet>, // Ordered by distance from arrival
+}
+
+impl StarSystem {
+    /// Returns a new instance of the *StarSystem* struct, built around a primary star of the given class.
+    /// Will use a random *StarClass* for the primary star if an invalid identifier is provided.
+    ///
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn new(name: String, class: &str) -> Self {
+        Self::from_rng(name, class, &mut rng())
+    }
+
+    /// Returns a new instance of the *StarSystem* struct, with every body drawn from the provided *Rng*.
+    /// The number of companion stars and planets depends on the class of the primary star,
+    /// and the stars are arranged into a hierarchy of orbiting pairs (see *OrbitalNode*).
+    ///
+    /// **Valid Identifiers:**
+    /// [O, OG, B, BG, A, AG, F, FG, G, GG, K, KG, M, MG, L, T, Y, AE/BE, TTS, C, CJ, CN, MS, S, W, WC, WN, WNC, WO, NS, D, DA, DAB, DAV, DAZ, DB, DBV, DBZ, DC, DCV, DQ, BH]
+    pub fn from_rng<R: Rng + ?Sized>(name: String, class: &str, rng: &mut R) -> Self {
+        let class: StarClass = StarClass::from_rng(class, rng);
+        Self::around_class(name, class, rng)
+    }
+
+    /// Returns a new instance of the *StarSystem* struct built around an already-generated primary *StarClass*.
+    pub fn around_class<R: Rng + ?Sized>(name: String, class: StarClass, rng: &mut R) -> Self {
+        let primary_star: Star = Star::from_class(format!("{} A", name), class, rng);
+
+        let companion_count: u32 = companion_count(&primary_star.class, rng);
+        let mut stars: Vec<Star> = vec![primary_star];
+        for i in 0..companion_count {
+            let designation = char::from(b'B' + i as u8);
+            stars.push(Star::from_rng(format!("{} {}", name, designation), "random", rng));
+        };
+        let hierarchy: OrbitalNode = build_hierarchy(&name, &mut stars, rng);
+        let mut stars = stars.into_iter();
+        let primary_star: Star = stars.next().expect("A system always has a primary star");
+        let companion_stars: Vec<Star> = stars.collect();
+
+        let planet_weights: PlanetTypeWeights = PlanetTypeWeights::for_star(&primary_star);
+        let planet_count: u32 = rng.gen_range(planet_count_range(&primary_star.class));
+        // Planets orbit the primary alone, so must stay well inside the orbit of its nearest companion.
+        let stable_limit: f64 = stable_limit(&hierarchy);
+        let mut planets: Vec<Planet> = (0..planet_count)
+            .filter_map(|_| {
+                let mut planet: Planet = Planet::from_weights(String::new(), &planet_weights, rng);
+                let placed: bool = (0..PLACEMENT_ATTEMPTS).any(|_| {
+                    planet.place_around(&primary_star, rng);
+                    planet.ptype.info().orbit.is_some_and(|orbit| orbit.apoapsis() <= stable_limit)
+                });
+                placed.then_some(planet)
+            })
+            .collect();
+
+        planets.sort_by(|a, b| a.stats().dist_from_arrival.total_cmp(&b.stats().dist_from_arrival));
+        for (i, planet) in planets.iter_mut().enumerate() {
+            planet.name = format!("{} {}", name, i + 1);
+            planet.generate_moons(&primary_star, rng);
+            planet.generate_species(&primary_star, rng);
+        };
+
+        StarSystem {
+            name,
+            primary_star,
+            companion_stars,
+            hierarchy,
+            planets,
+        }
+    }
+
+    /// Returns a randomly-initialized instance of the *StarSystem* struct generated from a *u64* seed.
+    /// The system is given a procedurally-generated name catalogued under the mass of its primary star.
+    /// The same seed will always yield the same system.
+    pub fn generate(seed: u64) -> Self {
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        let class: StarClass = StarClass::from_rng("random", &mut rng);
+        let mass_code: MassCode = MassCode::for_solar_masses(class.info().solar_masses);
+        let name: String = ProcGenName::random(mass_code, &mut rng).to_string();
+        Self::around_class(name, class, &mut rng)
+    }
+
+    /// Returns an iterator over every star in the system, starting with the primary star.
+    pub fn stars(&self) -> impl Iterator<Item = &Star> {
+        std::iter::once(&self.primary_star).chain(self.companion_stars.iter())
+    }
+
+    /// Returns an iterator over every barycentre in the stellar hierarchy, outermost first.
+    pub fn barycentres(&self) -> impl Iterator<Item = &Barycentre> {
+        self.hierarchy.walk().filter_map(|(_, node)| node.barycentre())
+    }
+
+    /// Returns the widest distance from the primary star, in light-seconds, at which planets can orbit it stably.
+    /// Infinite for a lone star.
+    pub fn stable_limit(&self) -> f64 {
+        stable_limit(&self.hierarchy)
+    }
+
+    /// Returns an iterator over every moon in the system, grouped by parent planet.
+    pub fn moons(&self) -> impl Iterator<Item = &Planet> {
+        self.planets.iter().flat_map(|planet| planet.moons.iter())
+    }
+
+    /// Returns the total number of stars, planets and moons in the system.
+    pub fn body_count(&self) -> usize {
+        1 + self.companion_stars.len() + self.planets.len() + self.moons().count()
+    }
+
+    /// Returns the position of the system barycentre relative to the primary star, in light-seconds, at *t* seconds after epoch.
+    pub fn barycentre_at(&self, t: f64) -> Vector3 {
+        barycentre_of(&self.states_at(t)).0
+    }
+
+    /// Returns the position of the named star, planet or moon relative to the system barycentre, in light-seconds, at *t* seconds after epoch.
+    /// Returns *None* if no body in the system has that name.
+    pub fn position_of(&self, name: &str, t: f64) -> Option<Vector3> {
+        let states: Vec<BodyState> = self.states_at(t);
+        let (barycentre, _) = barycentre_of(&states);
+        states.iter()
+            .find(|state| state.name == name)
+            .map(|state| state.position - barycentre)
+    }
+
+    /// Returns the velocity of the named star, planet or moon relative to the system barycentre, in kilometers per second, at *t* seconds after epoch.
+    /// Returns *None* if no body in the system has that name.
+    pub fn velocity_of(&self, name: &str, t: f64) -> Option<Vector3> {
+        let states: Vec<BodyState> = self.states_at(t);
+        let (_, barycentre_velocity) = barycentre_of(&states);
+        states.iter()
+            .find(|state| state.name == name)
+            .map(|state| state.velocity - barycentre_velocity)
+    }
+
+    /// Returns the distance in light-seconds between two named bodies at *t* seconds after epoch.
+    /// Returns *None* if either name does not belong to the system.
+    pub fn distance_between(&self, a: &str, b: &str, t: f64) -> Option<f64> {
+        let states: Vec<BodyState> = self.states_at(t);
+        let first: &BodyState = states.iter().find(|state| state.name == a)?;
+        let second: &BodyState = states.iter().find(|state| state.name == b)?;
+        Some(first.position.distance(&second.position))
+    }
+
+    /// Returns the total credit value of scanning every star, planet and moon in the system with the same options.
+    pub fn scan_value(&self, options: &ScanOptions) -> u64 {
+        self.stars().map(|star| star.scan_value(options)).sum::<u64>()
+            + self.planets.iter().chain(self.moons()).map(|planet| planet.scan_value(options)).sum::<u64>()
+    }
+
+    /// Returns the mass, position and velocity of every body relative to the primary star at time *t*.
+    fn states_at(&self, t: f64) -> Vec<BodyState<'_>> {
+        let star_states: Vec<(Vector3, Vector3)> = self.star_states_at(t);
+        let (origin_position, origin_velocity): (Vector3, Vector3) = star_states[0];
+        let stars = self.stars().zip(star_states).map(|(star, (position, velocity))| BodyState {
+            name: &star.name,
+            mass: star.class.info().solar_masses,
+            position: position - origin_position,
+            velocity: velocity - origin_velocity,
+        });
+
+        let planets = self.planets.iter().map(|planet| BodyState {
+            name: &planet.name,
+            mass: planet.ptype.info().earth_masses / EARTH_MASSES_PER_SOLAR_MASS,
+            position: planet.position_at(t).unwrap_or_default(),
+            velocity: planet.velocity_at(t).unwrap_or_default(),
+        });
+
+        let moons = self.planets.iter().flat_map(|planet| {
+            let parent_position: Vector3 = planet.position_at(t).unwrap_or_default();
+            let parent_velocity: Vector3 = planet.velocity_at(t).unwrap_or_default();
+            planet.moons.iter().map(move |moon| BodyState {
+                name: &moon.name,
+                mass: moon.ptype.info().earth_masses / EARTH_MASSES_PER_SOLAR_MASS,
+                position: parent_position + moon.position_at(t).unwrap_or_default(),
+                velocity: parent_velocity + moon.velocity_at(t).unwrap_or_default(),
+            })
+        });
+
+        stars.chain(planets).chain(moons).collect()
+    }
+
+    /// Returns the position and velocity of every star relative to the root of the hierarchy at time *t*,
+    /// in the same order as *stars()*.
+    fn star_states_at(&self, t: f64) -> Vec<(Vector3, Vector3)> {
+        let stars: Vec<&Star> = self.stars().collect();
+        let mut states: Vec<(Vector3, Vector3)> = vec![(Vector3::default(), Vector3::default()); stars.len()];
+        let mut stack: Vec<(&OrbitalNode, Vector3, Vector3)> = vec![(&self.hierarchy, Vector3::default(), Vector3::default())];
+        while let Some((node, position, velocity)) = stack.pop() {
+            match node {
+                OrbitalNode::Star { index, .. } => {
+                    let star: &Star = stars[*index];
+                    states[*index] = (
+                        position + star.position_at(t).unwrap_or_default(),
+                        velocity + star.velocity_at(t).unwrap_or_default(),
+                    );
+                },
+                OrbitalNode::Barycentre(barycentre) => {
+                    let (offset, drift): (Vector3, Vector3) = barycentre.orbit
+                        .map_or((Vector3::default(), Vector3::default()), |o| (o.position_at(t), o.velocity_at(t)));
+                    stack.push((&barycentre.primary, position + offset, velocity + drift));
+                    stack.push((&barycentre.secondary, position + offset, velocity + drift));
+                },
+            };
+        };
+        states
+    }
+}
+
+
+/// Snapshot of a single body's mass and motion relative to the primary star.
+struct BodyState<'a> {
+    name: &'a str,
+    mass: f64, // Solar Masses
+    position: Vector3, // Light-Seconds
+    velocity: Vector3, // km/s
+}
+
+
+/* System Util Functions */
+
+/// Arranges the stars of a system into a hierarchy of orbiting pairs, and sets every star's orbit about its barycentre.
+/// The primary and first companion always form the innermost pair. Each later companion either orbits everything
+/// before it, or pairs up with the companion after it so that the two orbit the inner stars together.
+/// Every outer orbit is drawn several times wider than the orbits it encloses, which keeps the hierarchy stable.
+fn build_hierarchy<R: Rng + ?Sized>(system_name: &str, stars: &mut [Star], rng: &mut R) -> OrbitalNode {
+    let mut root: OrbitalNode = star_node(stars, 0);
+    let mut i: usize = 1;
+    while i < stars.len() {
+        let unit: OrbitalNode = if i >= 2 && i + 1 < stars.len() && rng.gen_bool(0.4) {
+            let (primary, secondary) = (star_node(stars, i), star_node(stars, i + 1));
+            let separation: f64 = log_uniform(10.0, 1_000.0, rng);
+            i += 2;
+            pair(system_name, primary, secondary, separation, stars, rng)
+        }else {
+            i += 1;
+            star_node(stars, i - 1)
+        };
+
+        let enclosed: f64 = orbit_width(&root).max(orbit_width(&unit));
+        let separation: f64 = if enclosed > 0.0 {
+            enclosed * log_uniform(4.0, 40.0, rng)
+        }else {
+            log_uniform(10.0, 10_000.0, rng)
+        };
+        root = pair(system_name, root, unit, separation, stars, rng);
+    };
+    root
+}
+
+/// Returns *PLANET_STABILITY_FRACTION* of the closest approach of the innermost companion to the primary star,
+/// in light-seconds, or infinity if the primary has no companions.
+fn stable_limit(hierarchy: &OrbitalNode) -> f64 {
+    hierarchy.walk()
+        .filter_map(|(_, node)| node.barycentre())
+        .filter(|barycentre| barycentre.primary.star_indices().contains(&0) || barycentre.secondary.star_indices().contains(&0))
+        .map(|barycentre| barycentre.separation.periapsis() * PLANET_STABILITY_FRACTION)
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the leaf *OrbitalNode* for the star at the given index.
+fn star_node(stars: &[Star], index: usize) -> OrbitalNode {
+    OrbitalNode::Star {
+        index,
+        name: stars[index].name.clone(),
+        mass: stars[index].class.info().solar_masses,
+    }
+}
+
+/// Joins two nodes into a *Barycentre* with a random orbit at least *separation* light-seconds wide,
+/// keeping clear of the stars' surfaces, and gives each member its orbit about the new barycentre.
+fn pair<R: Rng + ?Sized>(system_name: &str, mut primary: OrbitalNode, mut secondary: OrbitalNode, separation: f64, stars: &mut [Star], rng: &mut R) -> OrbitalNode {
+    let mass: f64 = primary.mass() + secondary.mass();
+    let contact: f64 = 3.0 * (node_radius(&primary, stars) + node_radius(&secondary, stars));
+    let relative_orbit: OrbitalElements = OrbitalElements::random(separation.max(contact), mass, rng);
+
+    set_node_orbit(&mut primary, relative_orbit.about_barycentre(secondary.mass() / mass, true), stars);
+    set_node_orbit(&mut secondary, relative_orbit.about_barycentre(primary.mass() / mass, false), stars);
+
+    let mut indices: Vec<usize> = primary.star_indices();
+    indices.extend(secondary.star_indices());
+    indices.sort_unstable();
+    let designations: String = indices.iter().map(|i| char::from(b'A' + *i as u8)).collect();
+
+    OrbitalNode::Barycentre(Box::new(Barycentre {
+        name: format!("{} {}", system_name, designations),
+        mass,
+        primary,
+        secondary,
+        separation: relative_orbit,
+        orbit: None,
+    }))
+}
+
+/// Sets the orbit of a node about its parent barycentre, on the star itself for leaves.
+fn set_node_orbit(node: &mut OrbitalNode, orbit: OrbitalElements, stars: &mut [Star]) {
+    match node {
+        OrbitalNode::Star { index, .. } => stars[*index].set_orbit(orbit),
+        OrbitalNode::Barycentre(barycentre) => barycentre.orbit = Some(orbit),
+    };
+}
+
+/// Returns the summed radii, in light-seconds, of every star beneath a node.
+fn node_radius(node: &OrbitalNode, stars: &[Star]) -> f64 {
+    node.star_indices()
+        .iter()
+        .map(|i| stars[*i].class.info().solar_radius * SOLAR_RADIUS_LS)
+        .sum()
+}
+
+/// Returns the apoapsis of the widest orbit inside a node, or zero for a single star.
+fn orbit_width(node: &OrbitalNode) -> f64 {
+    node.barycentre().map_or(0.0, |b| b.separation.apoapsis())
+}
+
+/// Returns the mass-weighted position and velocity of the given bodies.
+fn barycentre_of(states: &[BodyState]) -> (Vector3, Vector3) {
+    let total_mass: f64 = states.iter().map(|state| state.mass).sum();
+    let position: Vector3 = states.iter().fold(Vector3::default(), |sum, state| sum + state.position * state.mass);
+    let velocity: Vector3 = states.iter().fold(Vector3::default(), |sum, state| sum + state.velocity * state.mass);
+    (position * (1.0 / total_mass), velocity * (1.0 / total_mass))
+}
+
+/// Returns a plausible range for the number of planets orbiting a star of the given class.
+fn planet_count_range(class: &StarClass) -> RangeInclusive<u32> {
+    match class {
+        StarClass::A(_) | StarClass::AG(_) | StarClass::F(_) | StarClass::FG(_)
+        | StarClass::G(_) | StarClass::GG(_) | StarClass::K(_) | StarClass::KG(_) => 0..=14,
+        StarClass::M(_) | StarClass::MG(_) => 0..=10,
+        StarClass::O(_) | StarClass::OG(_) | StarClass::B(_) | StarClass::BG(_) => 0..=8,
+        StarClass::C(_) | StarClass::CJ(_) | StarClass::CN(_) | StarClass::MS(_) | StarClass::S(_) => 0..=6,
+        StarClass::L(_) | StarClass::T(_) | StarClass::Y(_) => 0..=5,
+        StarClass::D(_) | StarClass::DA(_) | StarClass::DAB(_) | StarClass::DAV(_) | StarClass::DAZ(_)
+        | StarClass::DB(_) | StarClass::DBV(_) | StarClass::DBZ(_) | StarClass::DC(_) | StarClass::DCV(_)
+        | StarClass::DQ(_) => 0..=5,
+        StarClass::AEBE(_) | StarClass::TTS(_) => 0..=3,
+        StarClass::W(_) | StarClass::WC(_) | StarClass::WN(_) | StarClass::WNC(_) | StarClass::WO(_)
+        | StarClass::NS(_) => 0..=3,
+        StarClass::BH(_) => 0..=2,
+    }
+}
+
+/// Returns a random number of companion stars for a primary star of the given class.
+/// Massive stars are more likely to be found in multiple-star systems.
+fn companion_count<R: Rng + ?Sized>(class: &StarClass, rng: &mut R) -> u32 {
+    let multiplicity: f64 = match class {
+        StarClass::O(_) | StarClass::OG(_) | StarClass::B(_) | StarClass::BG(_) => 0.7,
+        StarClass::A(_) | StarClass::AG(_) | StarClass::F(_) | StarClass::FG(_) => 0.5,
+        StarClass::G(_) | StarClass::GG(_) | StarClass::K(_) | StarClass::KG(_) => 0.4,
+        StarClass::M(_) | StarClass::MG(_) => 0.25,
+        StarClass::L(_) | StarClass::T(_) | StarClass::Y(_) => 0.15,
+        _ => 0.3,
+    };
+
+    let mut count: u32 = 0;
+    while count < 3 && rng.gen_bool(multiplicity / (count + 1) as f64) {
+        count += 1;
+    };
+    count
+}